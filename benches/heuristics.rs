@@ -0,0 +1,116 @@
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use tempfile::TempDir;
+use typst_project::heuristics::{self, Heuristics, MANIFEST_FILE};
+use typst_project::manifest::Manifest;
+
+const MINIMAL_MANIFEST: &str = r#"
+[package]
+name = "foo"
+version = "0.1.0"
+entrypoint = "src/lib.typ"
+authors = ["John Doe"]
+license = "MIT"
+description = "Bar"
+"#;
+
+const FULL_MANIFEST: &str = r#"
+[package]
+name = "foo"
+version = "0.1.0"
+entrypoint = "src/lib.typ"
+authors = ["John Doe <john@doe.com>", "Martin <@reknih>", "tingerrr <https://tinger.dev>"]
+license = "MIT OR Apache-2.0"
+description = "Bar"
+homepage = "https://github.com/tingerrr/typst-project"
+repository = "https://github.com/tingerrr/typst-project"
+keywords = ["foo", "bar", "baz", "qux"]
+categories = ["layout", "text", "scripting"]
+disciplines = ["computer-science", "mathematics"]
+compiler = "0.11.0"
+exclude = ["tests", "docs", "assets"]
+
+[template]
+path = "template"
+entrypoint = "main.typ"
+thumbnail = "assets/thumbnail.png"
+
+[tool.typst-test]
+tests = "tests"
+
+[tool.packager]
+include = ["src", "README.md", "LICENSE"]
+"#;
+
+/// Creates a project root containing a manifest and `entries` other files.
+fn flat_dir(entries: usize) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for idx in 0..entries.saturating_sub(1) {
+        fs::write(dir.path().join(format!("file-{idx}.txt")), "").unwrap();
+    }
+    fs::write(dir.path().join(MANIFEST_FILE), MINIMAL_MANIFEST).unwrap();
+    dir
+}
+
+/// Creates a project root containing a manifest and `depth` nested
+/// directories, returns the deepest directory.
+fn nested_dir(depth: usize) -> (TempDir, PathBuf) {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(MANIFEST_FILE), MINIMAL_MANIFEST).unwrap();
+
+    let mut path = dir.path().to_path_buf();
+    for idx in 0..depth {
+        path.push(format!("dir-{idx}"));
+    }
+    fs::create_dir_all(&path).unwrap();
+
+    (dir, path)
+}
+
+fn project_root(c: &mut Criterion) {
+    let mut group = c.benchmark_group("project_root");
+    for entries in [10, 100, 1000] {
+        let dir = flat_dir(entries);
+        group.bench_with_input(BenchmarkId::from_parameter(entries), &dir, |b, dir| {
+            b.iter(|| heuristics::project_root(black_box(dir.path()), Heuristics::all(), false))
+        });
+    }
+    group.finish();
+}
+
+fn try_find_project_root(c: &mut Criterion) {
+    let mut group = c.benchmark_group("try_find_project_root");
+    for depth in [5, 20, 50] {
+        let (_dir, path) = nested_dir(depth);
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &path, |b, path| {
+            b.iter(|| {
+                heuristics::try_find_project_root(black_box(path), Heuristics::RECOMMENDED, true)
+                    .map(|r| r.is_some())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn manifest_from_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Manifest::from_str");
+    for (name, manifest) in [("minimal", MINIMAL_MANIFEST), ("full", FULL_MANIFEST)] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            manifest,
+            |b, manifest| b.iter(|| Manifest::from_str(black_box(manifest))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    project_root,
+    try_find_project_root,
+    manifest_from_str
+);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use std::fs;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use tempfile::TempDir;
+use typst_project::heuristics::{self, Heuristics, LIB_FILE, MAIN_FILE, MANIFEST_FILE};
+
+/// The root files of each directory composition, the remaining entries are
+/// filled with unrelated files.
+const COMPOSITIONS: &[(&str, &[&str])] = &[
+    ("manifest", &[MANIFEST_FILE]),
+    ("package", &[MANIFEST_FILE, LIB_FILE]),
+    (
+        "all",
+        &[MANIFEST_FILE, MAIN_FILE, LIB_FILE, "typstfmt.toml"],
+    ),
+];
+
+/// Creates a project root containing the given root files and `entries`
+/// entries in total, including a src folder with a lib.typ file.
+fn project_dir(root_files: &[&str], entries: usize) -> TempDir {
+    let dir = TempDir::new().unwrap();
+
+    let src = dir.path().join("src");
+    fs::create_dir(&src).unwrap();
+    fs::write(src.join(LIB_FILE), "").unwrap();
+
+    for file in root_files {
+        fs::write(dir.path().join(file), "").unwrap();
+    }
+
+    let rest = entries.saturating_sub(root_files.len() + 1);
+    for idx in 0..rest {
+        fs::write(dir.path().join(format!("file-{idx}.txt")), "").unwrap();
+    }
+
+    dir
+}
+
+fn project_root(c: &mut Criterion) {
+    let mut group = c.benchmark_group("project_root_first_vs_full");
+    for &(composition, root_files) in COMPOSITIONS {
+        for entries in [10, 100, 1000] {
+            let dir = project_dir(root_files, entries);
+
+            for (mode, first) in [("first", true), ("full", false)] {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("{composition}/{mode}"), entries),
+                    &dir,
+                    |b, dir| {
+                        b.iter(|| {
+                            heuristics::project_root(
+                                black_box(dir.path()),
+                                Heuristics::all(),
+                                first,
+                            )
+                        })
+                    },
+                );
+            }
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, project_root);
+criterion_main!(benches);
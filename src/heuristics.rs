@@ -1,6 +1,11 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::{fmt, fs, io};
 
+use serde::de::{Deserializer, Unexpected, Visitor};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
 /// The name of the typst manifest file.
 pub const MANIFEST_FILE: &str = "typst.toml";
 
@@ -37,6 +42,144 @@ pub enum Heuristic {
     TypstfmtConfig,
 }
 
+impl Heuristic {
+    /// All heuristics, in ascending order of [Heuristic::priority], ties are
+    /// ordered such that main files come last.
+    const ALL: &'static [Heuristic] = &[
+        #[cfg(feature = "heuristics-typstfmt")]
+        Heuristic::TypstfmtConfig,
+        Heuristic::LibFile { src: false },
+        Heuristic::MainFile { src: false },
+        Heuristic::LibFile { src: true },
+        Heuristic::MainFile { src: true },
+        Heuristic::ManifestFile,
+    ];
+
+    /// Converts a [Heuristic] into it's kebab-case text representation, this
+    /// is also used for serialization.
+    pub fn to_str(self) -> &'static str {
+        match self {
+            Heuristic::MainFile { src: false } => "main-file",
+            Heuristic::MainFile { src: true } => "src-main-file",
+            Heuristic::LibFile { src: false } => "lib-file",
+            Heuristic::LibFile { src: true } => "src-lib-file",
+            Heuristic::ManifestFile => "manifest-file",
+            #[cfg(feature = "heuristics-typstfmt")]
+            Heuristic::TypstfmtConfig => "typstfmt-config",
+        }
+    }
+
+    /// Returns `true` if this heuristic is matched by a file in the project
+    /// root, this is the complement of [Heuristic::is_directory_heuristic].
+    pub fn is_file_heuristic(self) -> bool {
+        !self.is_directory_heuristic()
+    }
+
+    /// Returns `true` if this heuristic is matched by a directory in the
+    /// project root, such as the src folder containing the entrypoint.
+    pub fn is_directory_heuristic(self) -> bool {
+        matches!(
+            self,
+            Heuristic::MainFile { src: true } | Heuristic::LibFile { src: true }
+        )
+    }
+
+    /// The name of the file this heuristic looks for, see [root_file_path]
+    /// for the full path of the file within a project root.
+    pub fn to_file_name(self) -> &'static str {
+        match self {
+            Heuristic::MainFile { .. } => MAIN_FILE,
+            Heuristic::LibFile { .. } => LIB_FILE,
+            Heuristic::ManifestFile => MANIFEST_FILE,
+            #[cfg(feature = "heuristics-typstfmt")]
+            Heuristic::TypstfmtConfig => "typstfmt.toml",
+        }
+    }
+
+    /// A score for how reliably this heuristic identifies a project root,
+    /// higher scores are more reliable. This can be used to pick the most
+    /// authoritative heuristic if a directory matches more than one.
+    ///
+    /// | Heuristic                      | Priority |
+    /// | ------------------------------ | -------- |
+    /// | `ManifestFile`                 | 100      |
+    /// | `MainFile`/`LibFile` in src    | 60       |
+    /// | `MainFile`/`LibFile` in root   | 50       |
+    /// | `TypstfmtConfig`               | 20       |
+    pub fn priority(self) -> u8 {
+        match self {
+            Heuristic::ManifestFile => 100,
+            Heuristic::MainFile { src: true } | Heuristic::LibFile { src: true } => 60,
+            Heuristic::MainFile { src: false } | Heuristic::LibFile { src: false } => 50,
+            #[cfg(feature = "heuristics-typstfmt")]
+            Heuristic::TypstfmtConfig => 20,
+        }
+    }
+}
+
+impl fmt::Display for Heuristic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/// An error returned when parsing an unknown heuristic name.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown heuristic {0:?}")]
+pub struct ParseHeuristicError(pub String);
+
+impl FromStr for Heuristic {
+    type Err = ParseHeuristicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "main-file" => Heuristic::MainFile { src: false },
+            "src-main-file" => Heuristic::MainFile { src: true },
+            "lib-file" => Heuristic::LibFile { src: false },
+            "src-lib-file" => Heuristic::LibFile { src: true },
+            "manifest-file" => Heuristic::ManifestFile,
+            #[cfg(feature = "heuristics-typstfmt")]
+            "typstfmt-config" => Heuristic::TypstfmtConfig,
+            _ => return Err(ParseHeuristicError(s.to_owned())),
+        })
+    }
+}
+
+impl Serialize for Heuristic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Heuristic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct HeuristicVisitor;
+
+        impl<'de> Visitor<'de> for HeuristicVisitor {
+            type Value = Heuristic;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a heuristic name")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Heuristic::from_str(v).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(HeuristicVisitor)
+    }
+}
+
 impl From<Heuristic> for Heuristics {
     fn from(value: Heuristic) -> Self {
         match value {
@@ -72,6 +215,16 @@ bitflags::bitflags! {
         #[cfg(feature = "heuristics-typstfmt")]
         const TYPSTFMT_CONFIG = 1 << 3;
 
+        /// The minimal heuristics, this only looks for typst.toml manifest
+        /// files and will not gain new heuristics in future versions.
+        const MINIMAL = Self::MANIFEST_FILE.bits();
+
+        /// The strict heuristics, this requires both a typst.toml manifest
+        /// file and a main.typ or lib.typ entrypoint in the root folder. A
+        /// directory only fully matches these if [project_root] returns all of
+        /// them, this is useful to confirm that a project is fully set up.
+        const STRICT = Self::MANIFEST_FILE.bits() | Self::MAIN_FILE.bits() | Self::LIB_FILE.bits();
+
         /// The recommended heuristics.
         #[cfg(not(feature = "heuristics-typstfmt"))]
         const RECOMMENDED = Self::MANIFEST_FILE.bits();
@@ -82,6 +235,96 @@ bitflags::bitflags! {
     }
 }
 
+impl Heuristics {
+    /// Creates a set of heuristics from the given flags, each flag enables
+    /// the heuristic of the same name. Note that [Heuristics::MAIN_FILE] and
+    /// [Heuristics::LIB_FILE] are currently the same flag.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::heuristics::Heuristics;
+    ///
+    /// assert_eq!(
+    ///     Heuristics::from_flags(true, true, false, false),
+    ///     Heuristics::MANIFEST_FILE | Heuristics::MAIN_FILE,
+    /// );
+    /// ```
+    pub fn from_flags(
+        manifest_file: bool,
+        main_file: bool,
+        lib_file: bool,
+        src_folder: bool,
+    ) -> Self {
+        let mut heuristics = Self::empty();
+        heuristics.set(Self::MANIFEST_FILE, manifest_file);
+        heuristics.set(Self::SRC_FOLDER, src_folder);
+
+        // main and lib share a bit, so unset flags must not clear each other
+        if main_file {
+            heuristics |= Self::MAIN_FILE;
+        }
+
+        if lib_file {
+            heuristics |= Self::LIB_FILE;
+        }
+
+        heuristics
+    }
+
+    /// Returns `true` if all of the `required` heuristics are contained in
+    /// this set, this is an alias for [Heuristics::contains].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use typst_project::heuristics::{project_root, Heuristics};
+    ///
+    /// let detected = project_root(".", Heuristics::STRICT, false)?;
+    /// if detected.satisfies_all(Heuristics::STRICT) {
+    ///     println!("Project is fully set up");
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn satisfies_all(&self, required: Heuristics) -> bool {
+        self.contains(required)
+    }
+
+    /// Returns `true` if any of the `required` heuristics are contained in
+    /// this set, this is an alias for [Heuristics::intersects].
+    pub fn satisfies_any(&self, required: Heuristics) -> bool {
+        self.intersects(required)
+    }
+
+    /// Returns the set heuristic with the highest [Heuristic::priority], or
+    /// `None` if no heuristic is set. Because [Heuristics::MAIN_FILE] and
+    /// [Heuristics::LIB_FILE] share a flag, their heuristics are reported as
+    /// [Heuristic::MainFile].
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::heuristics::{Heuristic, Heuristics};
+    ///
+    /// assert_eq!(
+    ///     (Heuristics::MAIN_FILE | Heuristics::MANIFEST_FILE).best_heuristic(),
+    ///     Some(Heuristic::ManifestFile),
+    /// );
+    /// assert_eq!(Heuristics::empty().best_heuristic(), None);
+    /// ```
+    pub fn best_heuristic(&self) -> Option<Heuristic> {
+        Heuristic::ALL
+            .iter()
+            .copied()
+            .filter(|&heuristic| self.contains(heuristic.into()))
+            .max_by_key(|heuristic| heuristic.priority())
+    }
+}
+
+/// The default heuristics are always [Heuristics::RECOMMENDED].
+impl Default for Heuristics {
+    fn default() -> Self {
+        Self::RECOMMENDED
+    }
+}
+
 impl fmt::Debug for Heuristics {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         bitflags::parser::to_writer(self, f)
@@ -127,6 +370,32 @@ pub fn try_find_project_root(
     Ok(None)
 }
 
+/// Recursively looks up the ancestors of `path` until it finds a directory
+/// containing a [typst.toml][MANIFEST_FILE] manifest file and returns the path
+/// to that manifest. See [try_find_project_root] for more info.
+///
+/// Returns `None` if no manifest can be found, returns an error if
+/// [try_find_project_root] fails.
+///
+/// # Examples
+/// ```no_run
+/// use typst_project::heuristics::find_nearest_manifest;
+/// use std::env::current_dir;
+///
+/// let pwd = current_dir()?;
+/// match find_nearest_manifest(&pwd)? {
+///     Some(manifest) => println!("Found manifest: {manifest:?}"),
+///     None => println!("No manifest found"),
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn find_nearest_manifest(path: &Path) -> io::Result<Option<PathBuf>> {
+    Ok(
+        try_find_project_root(path, Heuristics::MANIFEST_FILE, true)?
+            .map(|(root, _)| root.join(MANIFEST_FILE)),
+    )
+}
+
 /// Checks if a directory matches any of the given heuristics. See
 /// [project_root] if you need to know which heuristics were matched.
 ///
@@ -178,7 +447,7 @@ pub fn project_root<P: AsRef<Path>>(
         let mut res = Heuristics::empty();
 
         for entry in fs::read_dir(path)? {
-            if let Some(h) = potential_root_dir_entry(entry?, heuristics)? {
+            if let Some((h, _)) = potential_root_dir_entry(entry?, heuristics)? {
                 res |= h.into();
 
                 if first || res == heuristics {
@@ -193,30 +462,95 @@ pub fn project_root<P: AsRef<Path>>(
     inner(path.as_ref(), heuristics, first)
 }
 
+/// Checks a directory for all of the given heuristics and returns each
+/// matched heuristic together with the path of the entry which caused the
+/// match. The returned paths are always absolute, a relative `path` is made
+/// absolute using the current directory, see [`std::path::absolute`], symbolic
+/// links are not resolved.
+///
+/// Returns an error if `path` cannot be made absolute or if
+/// [read_dir][fs::read_dir] fails.
+///
+/// # Examples
+/// ```no_run
+/// use typst_project::heuristics::{scan_directory, Heuristics};
+/// use std::env::current_dir;
+///
+/// let pwd = current_dir()?;
+/// for (heuristic, path) in scan_directory(&pwd, Heuristics::all())? {
+///     println!("Found {heuristic:?} at {path:?}");
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn scan_directory(
+    path: &Path,
+    heuristics: Heuristics,
+) -> io::Result<Vec<(Heuristic, PathBuf)>> {
+    let path = std::path::absolute(path)?;
+    let mut res = vec![];
+
+    for entry in fs::read_dir(path)? {
+        if let Some(found) = potential_root_dir_entry(entry?, heuristics)? {
+            res.push(found);
+        }
+    }
+
+    Ok(res)
+}
+
+/// Returns the path of the file the given heuristic looks for in the project
+/// root `root`, this is `root` joined with [Heuristic::to_file_name] and the
+/// src folder if applicable.
+///
+/// Returns `None` for heuristics which don't correspond to a single file,
+/// currently all heuristics correspond to a single file.
+///
+/// # Examples
+/// ```
+/// use typst_project::heuristics::{root_file_path, Heuristic};
+/// use std::path::Path;
+///
+/// let root = Path::new("/project");
+/// assert_eq!(
+///     root_file_path(root, Heuristic::MainFile { src: true }),
+///     Some(root.join("src").join("main.typ")),
+/// );
+/// ```
+pub fn root_file_path(root: &Path, heuristic: Heuristic) -> Option<PathBuf> {
+    let name = heuristic.to_file_name();
+
+    Some(match heuristic {
+        Heuristic::MainFile { src: true } | Heuristic::LibFile { src: true } => {
+            root.join("src").join(name)
+        }
+        _ => root.join(name),
+    })
+}
+
 fn potential_root_dir_entry(
     entry: fs::DirEntry,
     heuristics: Heuristics,
-) -> io::Result<Option<Heuristic>> {
+) -> io::Result<Option<(Heuristic, PathBuf)>> {
     let typ = entry.file_type()?;
     let name = entry.file_name();
 
     if typ.is_dir() {
         if heuristics.contains(Heuristics::SRC_FOLDER) && name == "src" {
-            for entry in fs::read_dir(entry.path().join("src"))? {
+            for entry in fs::read_dir(entry.path())? {
                 let entry = entry?;
                 let typ = entry.file_type()?;
                 let name = entry.file_name();
 
                 if !typ.is_file() {
-                    return Ok(None);
+                    continue;
                 }
 
-                if name == "main.typ" {
-                    return Ok(Some(Heuristic::MainFile { src: true }));
+                if name == MAIN_FILE {
+                    return Ok(Some((Heuristic::MainFile { src: true }, entry.path())));
                 }
 
-                if name == "lib.typ" {
-                    return Ok(Some(Heuristic::LibFile { src: true }));
+                if name == LIB_FILE {
+                    return Ok(Some((Heuristic::LibFile { src: true }, entry.path())));
                 }
             }
         }
@@ -232,5 +566,182 @@ fn potential_root_dir_entry(
         .iter()
         .copied()
         .filter(|&(_, h)| heuristics.contains(h.into()))
-        .find_map(|(f, h)| (name == f).then_some(h)))
+        .find_map(|(f, h)| (name == f).then(|| (h, entry.path()))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn src_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("utils")).unwrap();
+        fs::write(src.join(LIB_FILE), "").unwrap();
+
+        assert_eq!(
+            project_root(dir.path(), Heuristics::SRC_FOLDER, false).unwrap(),
+            Heuristics::LIB_FILE | Heuristics::SRC_FOLDER
+        );
+
+        let utils = src.join("utils");
+        let (root, found) = try_find_project_root(&utils, Heuristics::SRC_FOLDER, true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(root, dir.path());
+        assert_eq!(found, Heuristics::LIB_FILE | Heuristics::SRC_FOLDER);
+    }
+
+    #[test]
+    fn serde() {
+        let heuristics = [
+            Heuristic::MainFile { src: false },
+            Heuristic::MainFile { src: true },
+            Heuristic::LibFile { src: false },
+            Heuristic::LibFile { src: true },
+            Heuristic::ManifestFile,
+        ];
+
+        for heuristic in heuristics {
+            let value = toml::Value::try_from(heuristic).unwrap();
+            assert_eq!(value.as_str(), Some(heuristic.to_str()));
+            assert_eq!(Heuristic::deserialize(value).unwrap(), heuristic);
+        }
+
+        assert_eq!(
+            toml::Value::try_from(Heuristic::ManifestFile).unwrap(),
+            toml::Value::String("manifest-file".into())
+        );
+        assert!(Heuristic::deserialize(toml::Value::String("manifest".into())).is_err());
+    }
+
+    #[test]
+    fn hash() {
+        let mut map = HashMap::new();
+        map.insert(Heuristics::MANIFEST_FILE, String::from("manifest"));
+        map.insert(Heuristics::SRC_FOLDER, String::from("src"));
+
+        assert_eq!(
+            map.get(&Heuristics::MANIFEST_FILE).map(String::as_str),
+            Some("manifest")
+        );
+        assert_eq!(map.get(&Heuristics::RECOMMENDED), None);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Heuristics::default(), Heuristics::RECOMMENDED);
+    }
+
+    #[test]
+    fn file_and_directory_heuristics() {
+        assert!(Heuristic::ManifestFile.is_file_heuristic());
+        assert!(Heuristic::MainFile { src: false }.is_file_heuristic());
+        assert!(Heuristic::LibFile { src: true }.is_directory_heuristic());
+        assert!(!Heuristic::LibFile { src: true }.is_file_heuristic());
+    }
+
+    #[test]
+    fn best_heuristic() {
+        assert!(Heuristic::ManifestFile.priority() > Heuristic::MainFile { src: true }.priority());
+        assert!(
+            Heuristic::LibFile { src: true }.priority()
+                > Heuristic::LibFile { src: false }.priority()
+        );
+
+        assert_eq!(Heuristics::empty().best_heuristic(), None);
+        assert_eq!(
+            Heuristics::STRICT.best_heuristic(),
+            Some(Heuristic::ManifestFile)
+        );
+        assert_eq!(
+            Heuristics::LIB_FILE.best_heuristic(),
+            Some(Heuristic::MainFile { src: false })
+        );
+        assert_eq!(
+            (Heuristics::MAIN_FILE | Heuristics::SRC_FOLDER).best_heuristic(),
+            Some(Heuristic::MainFile { src: true })
+        );
+    }
+
+    #[test]
+    fn root_file_path() {
+        let root = Path::new("project");
+        assert_eq!(
+            super::root_file_path(root, Heuristic::ManifestFile),
+            Some(root.join(MANIFEST_FILE))
+        );
+        assert_eq!(
+            super::root_file_path(root, Heuristic::LibFile { src: false }),
+            Some(root.join(LIB_FILE))
+        );
+        assert_eq!(
+            super::root_file_path(root, Heuristic::MainFile { src: true }),
+            Some(root.join("src").join(MAIN_FILE))
+        );
+    }
+
+    #[test]
+    fn strict() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(MANIFEST_FILE), "").unwrap();
+        assert_ne!(
+            project_root(dir.path(), Heuristics::STRICT, false).unwrap(),
+            Heuristics::STRICT
+        );
+
+        fs::write(dir.path().join(LIB_FILE), "").unwrap();
+        let detected = project_root(dir.path(), Heuristics::STRICT, false).unwrap();
+        assert_eq!(detected, Heuristics::STRICT);
+        assert!(detected.satisfies_all(Heuristics::STRICT));
+        assert!(Heuristics::MANIFEST_FILE.satisfies_any(Heuristics::STRICT));
+        assert!(!Heuristics::MANIFEST_FILE.satisfies_all(Heuristics::STRICT));
+    }
+
+    #[test]
+    fn scan_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(MANIFEST_FILE), "").unwrap();
+        fs::write(dir.path().join("README.md"), "").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::create_dir(dir.path().join("src").join("utils")).unwrap();
+        fs::write(dir.path().join("src").join(LIB_FILE), "").unwrap();
+
+        let mut found = super::scan_directory(dir.path(), Heuristics::all()).unwrap();
+        found.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+        assert_eq!(
+            found,
+            [
+                (
+                    Heuristic::LibFile { src: true },
+                    dir.path().join("src").join(LIB_FILE)
+                ),
+                (Heuristic::ManifestFile, dir.path().join(MANIFEST_FILE)),
+            ]
+        );
+
+        let found = super::scan_directory(dir.path(), Heuristics::MANIFEST_FILE).unwrap();
+        assert_eq!(
+            found,
+            [(Heuristic::ManifestFile, dir.path().join(MANIFEST_FILE))]
+        );
+    }
+
+    #[test]
+    fn scan_directory_relative() {
+        let cwd = std::env::current_dir().unwrap();
+        let dir = tempfile::tempdir_in(&cwd).unwrap();
+        fs::write(dir.path().join(MANIFEST_FILE), "").unwrap();
+
+        let relative = dir.path().strip_prefix(&cwd).unwrap();
+        let found = super::scan_directory(relative, Heuristics::MANIFEST_FILE).unwrap();
+        assert_eq!(
+            found,
+            [(Heuristic::ManifestFile, dir.path().join(MANIFEST_FILE))]
+        );
+    }
 }
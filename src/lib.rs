@@ -1,6 +1,7 @@
 use std::io;
 use std::path::Path;
 
+pub use heuristics::find_nearest_manifest;
 use heuristics::Heuristics;
 
 pub mod heuristics;
@@ -1,15 +1,25 @@
 use std::fmt::{Debug, Display};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{fs, io};
 
+use semver::{Comparator, Op, Version, VersionReq};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 pub use toml::de::Error as DeserializeError;
 pub use toml::ser::Error as SerializeError;
-use toml::Table;
+use toml::{Table, Value};
+use toml_edit::{DocumentMut, TableLike, TomlError};
 
+use self::author::{Author, Contact, ParseAuthorError};
+use self::categories::Category;
+use self::disciplines::Discipline;
+use self::ident::ParseIdentError;
+use self::license::ParseLicenseError;
 use self::package::Package;
 use self::template::Template;
 use self::tool::Tool;
+use self::validation::ValidationError;
+use self::website::ParseWebsiteError;
 use crate::heuristics;
 use crate::heuristics::Heuristics;
 
@@ -19,12 +29,22 @@ pub mod disciplines;
 pub mod ident;
 pub mod license;
 pub mod package;
+pub mod package_id;
+pub mod package_ref;
+pub mod partial;
+pub mod summary;
 pub mod template;
 pub mod tool;
+pub mod validation;
 pub mod website;
 
 /// A typst.toml manifest.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Manifests can only be compared using [`PartialEq`], because the `tool` key
+/// may contain floating point values. See [`Manifest::is_same_package_version`]
+/// to only compare the package name and version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(deny_unknown_fields)]
 pub struct Manifest {
     /// The `package` key, storing a package's metadata.
@@ -37,6 +57,7 @@ pub struct Manifest {
 
     /// The `tool` key, storing 3rd-party configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub tool: Option<Tool>,
 }
 
@@ -64,7 +85,9 @@ impl Manifest {
     ///
     /// Returns `None` if no manifest could be found, returns an error if
     /// [heuristics::try_find_project_root] fails, or if a manifest was found
-    /// but could not be parsed.
+    /// but could not be parsed. Parse errors are returned as [`Error::InFile`]
+    /// containing the path of the manifest, rather than as [`Error::De`], see
+    /// [`Error::kind`] to classify them.
     ///
     /// # Examples
     /// ```no_run
@@ -87,10 +110,305 @@ impl Manifest {
             return Ok(None);
         };
 
-        let content = fs::read_to_string(root.join(heuristics::MANIFEST_FILE))?;
-        let manifest = Manifest::from_str(&content)?;
+        let path = root.join(heuristics::MANIFEST_FILE);
+        let content = fs::read_to_string(&path)?;
+        let manifest = Manifest::from_str_with_path(&content, path)?;
         Ok(Some(manifest))
     }
+
+    /// An iterator over the package's authors, see
+    /// [`Package::authors_sorted`] for the order.
+    pub fn authors_iter(&self) -> impl Iterator<Item = &Author> {
+        self.package.authors_sorted().into_iter()
+    }
+
+    /// The email addresses of the package's authors in the order of
+    /// [`Manifest::authors_iter`], authors without an email contact are
+    /// skipped.
+    pub fn authors_emails(&self) -> Vec<String> {
+        self.authors_iter()
+            .filter_map(|author| match &author.contact {
+                Some(Contact::Email(email)) => Some(email.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// An iterator over the package's categories, see
+    /// [`Package::categories_sorted`] for the order.
+    pub fn categories_iter(&self) -> impl Iterator<Item = Category> {
+        self.package.categories_sorted().into_iter()
+    }
+
+    /// An iterator over the package's disciplines, see
+    /// [`Package::disciplines_sorted`] for the order.
+    pub fn disciplines_iter(&self) -> impl Iterator<Item = Discipline> {
+        self.package.disciplines_sorted().into_iter()
+    }
+
+    /// Sets the `package.version` key of the manifest file at `path` to
+    /// `new_version`, preserving the formatting and comments of the file. See
+    /// [`Manifest::update_field_in_file`] for more info.
+    ///
+    /// Returns an error if the file could not be read, parsed or written.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use typst_project::manifest::Manifest;
+    /// use semver::Version;
+    ///
+    /// Manifest::update_version_in_file("typst.toml", Version::new(0, 2, 0))?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn update_version_in_file<P: AsRef<Path>>(
+        path: P,
+        new_version: Version,
+    ) -> Result<(), Error> {
+        Self::update_field_in_file(path, &["package", "version"], new_version.to_string())
+    }
+
+    /// Returns a clone of this manifest with the package version set to
+    /// `new_version`, see [`Manifest::update_version_in_file`] to update the
+    /// version of a manifest file in place.
+    pub fn clone_with_version(&self, new_version: Version) -> Manifest {
+        let mut manifest = self.clone();
+        manifest.package.version = new_version;
+        manifest
+    }
+
+    /// Returns a clone of this manifest with the `tool` key of `other` merged
+    /// into its own, see [`Tool::merge`]. The values of `other` take
+    /// precedence, all other keys are taken from this manifest.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let package = r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    /// "#;
+    ///
+    /// let root = Manifest::from_str(&format!("{package}\n[tool.foo]\na = 1\nb = 1"))?;
+    /// let member = Manifest::from_str(&format!("{package}\n[tool.foo]\nb = 2"))?;
+    ///
+    /// let merged = root.merge_tool_configs(&member);
+    /// let foo = merged.tool.as_ref().and_then(|tool| tool.get_table("foo")).unwrap();
+    /// assert_eq!(foo["a"].as_integer(), Some(1));
+    /// assert_eq!(foo["b"].as_integer(), Some(2));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn merge_tool_configs(&self, other: &Manifest) -> Manifest {
+        let mut manifest = self.clone();
+        if let Some(other) = &other.tool {
+            manifest.tool.get_or_insert_with(Tool::default).merge(other);
+        }
+
+        manifest
+    }
+
+    /// Returns `true` if both manifests describe a package with the same name
+    /// and version, regardless of any other metadata.
+    pub fn is_same_package_version(&self, other: &Manifest) -> bool {
+        self.package.name == other.package.name && self.package.version == other.package.version
+    }
+
+    /// Sets the field at the given key path of the manifest file at `path` to
+    /// `value`, preserving the formatting and comments of the file. Missing
+    /// tables along the key path are created. The resulting manifest is not
+    /// validated.
+    ///
+    /// Returns an error if the file could not be read, parsed or written, or if
+    /// a key along the path exists but is not a table.
+    ///
+    /// # Panics
+    /// Panics if `key` is empty.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// Manifest::update_field_in_file("typst.toml", &["tool", "typst-test", "tests"], "tests")?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn update_field_in_file<P: AsRef<Path>>(
+        path: P,
+        key: &[&str],
+        value: impl Into<toml_edit::Value>,
+    ) -> Result<(), Error> {
+        let (last, parents) = key.split_last().expect("key must not be empty");
+
+        let path = path.as_ref();
+        let mut doc: DocumentMut = fs::read_to_string(path)?.parse()?;
+
+        let mut table: &mut dyn TableLike = doc.as_table_mut();
+        for (idx, parent) in parents.iter().enumerate() {
+            table = table
+                .entry(parent)
+                .or_insert_with(|| {
+                    let mut table = toml_edit::Table::new();
+                    table.set_implicit(true);
+                    toml_edit::Item::Table(table)
+                })
+                .as_table_like_mut()
+                .ok_or_else(|| Error::NotATable(key[..=idx].join(".")))?;
+        }
+
+        let mut value = value.into();
+        match table.get_mut(last) {
+            Some(toml_edit::Item::Value(old)) => {
+                *value.decor_mut() = old.decor().clone();
+                *old = value;
+            }
+            _ => {
+                table.insert(last, toml_edit::Item::Value(value));
+            }
+        }
+
+        fs::write(path, doc.to_string())?;
+        Ok(())
+    }
+
+    /// Reads the manifest in the project root `dir`, unlike
+    /// [`Manifest::try_find`] this does not look for the manifest in any
+    /// ancestors of `dir`.
+    ///
+    /// Returns an error if the manifest could not be read, this includes the
+    /// case where it doesn't exist, or if it could not be parsed. Parse errors
+    /// are returned as [`Error::InFile`] containing the path of the manifest.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use typst_project::manifest::Manifest;
+    /// use std::env::current_dir;
+    ///
+    /// let pwd = current_dir()?;
+    /// let manifest = Manifest::from_directory(pwd)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_directory<P: AsRef<Path>>(dir: P) -> Result<Manifest, Error> {
+        let path = dir.as_ref().join(heuristics::MANIFEST_FILE);
+        let content = fs::read_to_string(&path)?;
+        Manifest::from_str_with_path(&content, path)
+    }
+
+    /// Checks this manifest for semantic errors which are not caught during
+    /// parsing, such as absolute paths or missing authors.
+    ///
+    /// Returns all found errors if the manifest is not valid.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let errors = validation::validate(self);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks whether the given typst version satisfies the minimum compiler
+    /// version of this manifest's package.
+    ///
+    /// Returns an error if the package requires a newer compiler than
+    /// `typst_version`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    /// use semver::Version;
+    ///
+    /// let manifest = Manifest::from_str(r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    ///     compiler = "0.11.0"
+    /// "#)?;
+    ///
+    /// assert!(manifest.check_compiler_compatibility(&Version::new(0, 11, 1)).is_ok());
+    /// assert!(manifest.check_compiler_compatibility(&Version::new(0, 10, 0)).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn check_compiler_compatibility(
+        &self,
+        typst_version: &Version,
+    ) -> Result<(), IncompatibleCompilerError> {
+        let Some(compiler) = &self.package.compiler else {
+            return Ok(());
+        };
+
+        // NOTE: we compare the versions directly instead of using
+        // VersionReq::matches, which would reject all pre-release compilers
+        if typst_version >= compiler {
+            return Ok(());
+        }
+
+        let required = VersionReq {
+            comparators: vec![Comparator {
+                op: Op::GreaterEq,
+                major: compiler.major,
+                minor: Some(compiler.minor),
+                patch: Some(compiler.patch),
+                pre: compiler.pre.clone(),
+            }],
+        };
+
+        Err(IncompatibleCompilerError {
+            required,
+            found: typst_version.clone(),
+        })
+    }
+
+    /// Returns a canonical form of this manifest, such that two logically
+    /// identical manifests compare equal and serialize to the same TOML.
+    ///
+    /// The package's fields are normalized, see [`Package::normalize_fields`].
+    /// Versions are already canonical after parsing and are kept as is. Sets
+    /// are always serialized in sorted order, regardless of normalization.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let a = Manifest::from_str(r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    ///     keywords = ["a", "b"]
+    /// "#)?;
+    ///
+    /// let b = Manifest::from_str(r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = [" John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "  Bar "
+    ///     keywords = ["b ", "a"]
+    /// "#)?;
+    ///
+    /// assert_ne!(a, b);
+    /// assert_eq!(a.normalize(), b.normalize());
+    /// assert_eq!(a.normalize().to_toml_string()?, b.normalize().to_toml_string()?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn normalize(&self) -> Manifest {
+        let mut manifest = self.clone();
+        manifest.package.normalize_fields();
+        manifest
+    }
 }
 
 impl Manifest {
@@ -141,9 +459,213 @@ impl Manifest {
     /// let manifest = Manifest::from_str(toml)?;
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(toml: &str) -> Result<Self, DeserializeError> {
         toml::from_str(toml)
     }
+
+    /// Deserializes a manifest from the contents of the manifest file at
+    /// `path`, the file itself is not read.
+    ///
+    /// Returns an error if deserialization fails, the error contains `path` as
+    /// context, see [`Error::path`].
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    /// use std::path::Path;
+    ///
+    /// let err = Manifest::from_str_with_path("[package]", "typst.toml".into()).unwrap_err();
+    /// assert_eq!(err.path(), Some(Path::new("typst.toml")));
+    /// ```
+    pub fn from_str_with_path(toml: &str, path: PathBuf) -> Result<Self, Error> {
+        Self::from_str(toml).map_err(|err| Error::InFile {
+            path,
+            source: Box::new(err.into()),
+        })
+    }
+
+    /// Serializes a manifest into a [`Table`][toml::Table].
+    ///
+    /// Returns a error if serialization fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let toml = r#"
+    ///     [package]
+    ///     name = "Foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    /// "#;
+    ///
+    /// let manifest = Manifest::from_str(toml)?;
+    /// let table = manifest.to_toml_table()?;
+    /// assert_eq!(table["package"]["name"].as_str(), Some("Foo"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_toml_table(&self) -> Result<Table, SerializeError> {
+        match Value::try_from(self)? {
+            Value::Table(table) => Ok(table),
+            _ => unreachable!("manifests always serialize to a table"),
+        }
+    }
+
+    /// Serializes a manifest into the contents of a manifest file.
+    ///
+    /// Returns a error if serialization fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let toml = r#"
+    ///     [package]
+    ///     name = "Foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    /// "#;
+    ///
+    /// let manifest = Manifest::from_str(toml)?;
+    /// let string = manifest.to_toml_string()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_toml_string(&self) -> Result<String, SerializeError> {
+        toml::to_string(self)
+    }
+}
+
+/// Writes the TOML serialization of a manifest, see
+/// [`Manifest::to_toml_string`]. Formatting fails if serialization fails.
+impl Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let toml = self.to_toml_string().map_err(|_| std::fmt::Error)?;
+        f.write_str(&toml)
+    }
+}
+
+#[cfg(feature = "json")]
+impl Manifest {
+    /// Serializes a manifest into a JSON [`Value`][serde_json::Value].
+    ///
+    /// Returns a error if serialization fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let toml = r#"
+    ///     [package]
+    ///     name = "Foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    /// "#;
+    ///
+    /// let manifest = Manifest::from_str(toml)?;
+    /// let json = manifest.to_json_value()?;
+    /// assert_eq!(json["package"]["name"], "Foo");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_json_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    /// Deserializes a manifest from a JSON string.
+    ///
+    /// Returns a error if deserialization fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let json = r#"{
+    ///     "package": {
+    ///         "name": "Foo",
+    ///         "version": "0.1.0",
+    ///         "entrypoint": "src/lib.typ",
+    ///         "authors": ["John Doe <john@doe.com>"],
+    ///         "license": "MIT",
+    ///         "description": "Bar"
+    ///     }
+    /// }"#;
+    ///
+    /// let manifest = Manifest::from_json_str(json)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Reads, parses and validates the manifest file at `path`.
+///
+/// Returns an error if the file could not be read or parsed, or if the parsed
+/// manifest is not valid. See [`Manifest::validate`] for more info. Parse
+/// errors contain `path` as context, see [`ManifestFileError::path`].
+///
+/// # Examples
+/// ```no_run
+/// use typst_project::manifest::validate_manifest_file;
+///
+/// let manifest = validate_manifest_file("typst.toml")?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate_manifest_file(path: impl AsRef<Path>) -> Result<Manifest, ManifestFileError> {
+    let path = path.as_ref();
+    let content = fs::read_to_string(path)?;
+    let manifest = Manifest::from_str_with_path(&content, path.to_owned())?;
+    manifest.validate().map_err(ManifestFileError::Invalid)?;
+    Ok(manifest)
+}
+
+/// An error returned by [`validate_manifest_file`].
+#[derive(Debug, Error)]
+pub enum ManifestFileError {
+    /// The manifest file could not be read.
+    #[error("an I/O error occured")]
+    Io(#[from] io::Error),
+
+    /// The manifest file could not be parsed, this is an [`Error::InFile`]
+    /// containing the path of the manifest file.
+    #[error("deserialization failed")]
+    De(#[from] Error),
+
+    /// The manifest is not valid.
+    #[error("manifest is invalid")]
+    Invalid(Vec<ValidationError>),
+}
+
+impl ManifestFileError {
+    /// The path of the manifest file this error occured in, if it is known.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::De(err) => err.path(),
+            _ => None,
+        }
+    }
+}
+
+/// An error returned by [`Manifest::check_compiler_compatibility`] if the
+/// given typst version does not satisfy the package's minimum compiler
+/// version.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("package requires compiler version {required}, found {found}")]
+pub struct IncompatibleCompilerError {
+    /// The version requirement given by the package.
+    pub required: VersionReq,
+
+    /// The version which was checked against the requirement.
+    pub found: Version,
 }
 
 /// An error that may occur during manifest discovery or parsing.
@@ -157,6 +679,79 @@ pub enum Error {
 
     /// A deserialization error occured.
     De(DeserializeError),
+
+    /// An author could not be parsed.
+    Author(ParseAuthorError),
+
+    /// A license could not be parsed.
+    License(ParseLicenseError),
+
+    /// An identifier could not be parsed.
+    Ident(ParseIdentError),
+
+    /// A website could not be parsed.
+    Website(ParseWebsiteError),
+
+    /// A manifest could not be parsed for editing.
+    Edit(TomlError),
+
+    /// A key which was expected to be a table was not a table.
+    NotATable(String),
+
+    /// An error occured in the manifest file at the given path.
+    ///
+    /// This is returned by [`Manifest::from_str_with_path`] and the functions
+    /// reading manifest files, such as [`Manifest::try_find`], these wrap the
+    /// underlying error, such as [`Error::De`], in this variant.
+    InFile {
+        /// The path of the manifest file.
+        path: PathBuf,
+
+        /// The error which occured in the file.
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// The kind of this error, this classification is stable even if new
+    /// variants are added to [`Error`][enum@Error].
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Io(_) => ErrorKind::Io,
+            Self::Ser(_) => ErrorKind::Serialization,
+            Self::De(_)
+            | Self::Author(_)
+            | Self::License(_)
+            | Self::Ident(_)
+            | Self::Website(_)
+            | Self::Edit(_)
+            | Self::NotATable(_) => ErrorKind::Deserialization,
+            Self::InFile { source, .. } => source.kind(),
+        }
+    }
+
+    /// The path of the manifest file this error occured in, if it is known.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::InFile { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// A coarse classification of an [`Error`][enum@Error], see [`Error::kind`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A generic I/O error occured.
+    Io,
+
+    /// A manifest could not be serialized.
+    Serialization,
+
+    /// A manifest could not be read, this includes invalid values and
+    /// unexpected structure of a manifest file which is edited.
+    Deserialization,
 }
 
 impl Display for Error {
@@ -165,6 +760,15 @@ impl Display for Error {
             Self::Io(_) => "an I/O error occured",
             Self::Ser(_) => "serialization failed",
             Self::De(_) => "deserialization failed",
+            Self::Author(_) => "invalid author",
+            Self::License(_) => "invalid license",
+            Self::Ident(_) => "invalid identifier",
+            Self::Website(_) => "invalid website",
+            Self::Edit(_) => "parsing for editing failed",
+            Self::NotATable(key) => return write!(f, "key `{key}` is not a table"),
+            Self::InFile { path, .. } => {
+                return write!(f, "error in manifest `{}`", path.display())
+            }
         })
     }
 }
@@ -175,6 +779,13 @@ impl std::error::Error for Error {
             Error::Io(err) => err,
             Error::Ser(err) => err,
             Error::De(err) => err,
+            Error::Author(err) => err,
+            Error::License(err) => err,
+            Error::Ident(err) => err,
+            Error::Website(err) => err,
+            Error::Edit(err) => err,
+            Error::NotATable(_) => return None,
+            Error::InFile { source, .. } => &**source,
         })
     }
 }
@@ -192,3 +803,329 @@ macro_rules! impl_from {
 impl_from!(io::Error => Io);
 impl_from!(SerializeError => Ser);
 impl_from!(DeserializeError => De);
+impl_from!(ParseAuthorError => Author);
+impl_from!(ParseLicenseError => License);
+impl_from!(ParseIdentError => Ident);
+impl_from!(ParseWebsiteError => Website);
+impl_from!(TomlError => Edit);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let manifest = Manifest::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = ["John Doe <john@doe.com>", "Martin <@reknih>"]
+            license = "MIT OR Apache-2.0"
+            description = "Bar"
+            homepage = "https://github.com/tingerrr/typst-project"
+            categories = ["layout"]
+
+            [tool.typst-test]
+            tests = "tests"
+            "#,
+        )
+        .unwrap();
+
+        let table = manifest.to_toml_table().unwrap();
+        assert_eq!(
+            table["package"]["license"].as_str(),
+            Some("MIT OR Apache-2.0")
+        );
+        assert_eq!(table["tool"]["typst-test"]["tests"].as_str(), Some("tests"));
+
+        let string = manifest.to_toml_string().unwrap();
+        let roundtrip = Manifest::from_str(&string).unwrap();
+        assert_eq!(roundtrip.package.name, manifest.package.name);
+        assert_eq!(roundtrip.package.authors, manifest.package.authors);
+        assert_eq!(roundtrip.package.license, manifest.package.license);
+        assert_eq!(roundtrip.package.homepage, manifest.package.homepage);
+        assert_eq!(roundtrip.package.categories, manifest.package.categories);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_roundtrip() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut seed = 0x2545f4914f6cdd1d_u64;
+        for _ in 0..100 {
+            let bytes: Vec<u8> = (0..512)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed as u8
+                })
+                .collect();
+
+            let manifest = Manifest::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            let string = manifest.to_toml_string().unwrap();
+            let roundtrip = Manifest::from_str(&string).unwrap();
+            assert_eq!(roundtrip.package.authors, manifest.package.authors);
+            assert_eq!(roundtrip.package.homepage, manifest.package.homepage);
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        let manifest = Manifest::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = ["Martin <https://mha.ug/>", " John Doe <john@doe.com>"]
+            license = "MIT"
+            description = " Bar"
+            repository = "https://github.com/tingerrr/typst-project/"
+            keywords = ["c", "a ", "b"]
+            categories = ["visualization", "layout"]
+            "#,
+        )
+        .unwrap()
+        .normalize();
+
+        assert_eq!(manifest.package.description, "Bar");
+        assert_eq!(
+            manifest.package.repository.as_deref(),
+            Some("https://github.com/tingerrr/typst-project")
+        );
+
+        let table = manifest.to_toml_table().unwrap();
+        let strings = |key: &str| -> Vec<_> {
+            table["package"][key]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|v| v.as_str().unwrap().to_owned())
+                .collect()
+        };
+
+        assert_eq!(
+            strings("authors"),
+            ["John Doe <john@doe.com>", "Martin <https://mha.ug/>"]
+        );
+        assert_eq!(strings("keywords"), ["a", "b", "c"]);
+        assert_eq!(strings("categories"), ["layout", "visualization"]);
+    }
+
+    #[test]
+    fn display() {
+        let manifest = Manifest::package(package::tests::package());
+        assert_eq!(manifest.to_string(), manifest.to_toml_string().unwrap());
+    }
+
+    #[test]
+    fn clone_with_version() {
+        let manifest = Manifest::package(package::tests::package());
+        let bumped = manifest.clone_with_version(Version::new(0, 2, 0));
+
+        assert_eq!(bumped.package.version, Version::new(0, 2, 0));
+        assert_eq!(manifest.package.version, Version::new(0, 1, 0));
+        assert_eq!(bumped.package.name, manifest.package.name);
+    }
+
+    #[test]
+    fn eq() {
+        let manifest = Manifest::package(package::tests::package());
+        let mut other = manifest.clone();
+        other.package.description = "Qux".into();
+
+        assert_ne!(manifest, other);
+        assert!(manifest.is_same_package_version(&other));
+        assert!(
+            !manifest.is_same_package_version(&manifest.clone_with_version(Version::new(0, 2, 0)))
+        );
+
+        let authors = ["John Doe", "Jane Doe"].map(Author::from_name);
+        let a = package::tests::package()
+            .with_author(authors[0].clone())
+            .with_author(authors[1].clone());
+        let b = package::tests::package()
+            .with_author(authors[1].clone())
+            .with_author(authors[0].clone());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn merge_tool_configs() {
+        let tool = |toml: toml::Table| Some(Tool::from(toml));
+
+        let mut root = Manifest::package(package::tests::package());
+        root.tool = tool(toml::toml! {
+            [foo]
+            a = 1
+            nested = { a = 1, b = 1 }
+
+            [bar]
+            a = 1
+        });
+
+        let mut member = Manifest::package(package::tests::package().with_description("Qux"));
+        member.tool = tool(toml::toml! {
+            [foo]
+            a = 2
+            nested = { b = 2 }
+
+            [baz]
+            a = 2
+        });
+
+        let merged = root.merge_tool_configs(&member);
+        assert_eq!(merged.package, root.package);
+        assert_eq!(
+            merged.tool,
+            tool(toml::toml! {
+                [foo]
+                a = 2
+                nested = { a = 1, b = 2 }
+
+                [bar]
+                a = 1
+
+                [baz]
+                a = 2
+            })
+        );
+
+        let empty = Manifest::package(package::tests::package());
+        assert_eq!(empty.merge_tool_configs(&empty).tool, None);
+        assert_eq!(empty.merge_tool_configs(&root).tool, root.tool);
+        assert_eq!(root.merge_tool_configs(&empty).tool, root.tool);
+    }
+
+    #[test]
+    fn error_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(heuristics::MANIFEST_FILE);
+        fs::write(&path, "[package]").unwrap();
+
+        let err = Manifest::try_find(dir.path()).unwrap_err();
+        assert_eq!(err.path(), Some(path.as_path()));
+        assert!(matches!(&err, Error::InFile { source, .. } if matches!(**source, Error::De(_))));
+
+        let err = Manifest::from_directory(dir.path()).unwrap_err();
+        assert_eq!(err.path(), Some(path.as_path()));
+
+        let err = validate_manifest_file(&path).unwrap_err();
+        assert!(matches!(err, ManifestFileError::De(_)));
+        assert_eq!(err.path(), Some(path.as_path()));
+    }
+
+    #[test]
+    fn error_kind() {
+        let err = Error::from(io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(err.kind(), ErrorKind::Io);
+
+        let err = Error::from(Manifest::from_str("[package]").unwrap_err());
+        assert_eq!(err.kind(), ErrorKind::Deserialization);
+
+        let err = Error::NotATable("tool".into());
+        assert_eq!(err.kind(), ErrorKind::Deserialization);
+
+        let err = Manifest::from_str_with_path("[package]", "typst.toml".into()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Deserialization);
+    }
+
+    #[test]
+    fn authors_emails() {
+        let manifest = Manifest::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = ["John Doe <john@doe.com>", "Martin <@reknih>", "Jane Doe <jane@doe.com>", "tingerrr"]
+            license = "MIT"
+            description = "Bar"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.authors_emails(), ["jane@doe.com", "john@doe.com"]);
+
+        let mut manifest = Manifest::package(package::tests::package());
+        manifest.package.authors = manifest
+            .package
+            .authors
+            .into_iter()
+            .map(Author::without_contact)
+            .collect();
+        assert!(manifest.authors_emails().is_empty());
+    }
+
+    #[test]
+    fn iters() {
+        let manifest = Manifest::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = ["Martin <@reknih>", "Laurenz", "Martin"]
+            license = "MIT"
+            description = "Bar"
+            categories = ["text", "book", "layout"]
+            disciplines = ["physics", "biology"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest
+                .authors_iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["Laurenz", "Martin", "Martin <@reknih>"]
+        );
+        assert_eq!(
+            manifest.categories_iter().collect::<Vec<_>>(),
+            [Category::Book, Category::Layout, Category::Text]
+        );
+        assert_eq!(
+            manifest.disciplines_iter().collect::<Vec<_>>(),
+            [Discipline::Biology, Discipline::Physics]
+        );
+    }
+
+    #[test]
+    fn update_field_in_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(heuristics::MANIFEST_FILE);
+        fs::write(
+            &path,
+            r#"# my package
+[package]
+name = "foo"
+version = "0.1.0" # bump me
+"#,
+        )
+        .unwrap();
+
+        Manifest::update_version_in_file(&path, Version::new(0, 2, 0)).unwrap();
+        Manifest::update_field_in_file(&path, &["tool", "foo", "bar"], 42).unwrap();
+        assert!(matches!(
+            Manifest::update_field_in_file(&path, &["package", "name", "bar"], 42),
+            Err(Error::NotATable(key)) if key == "package.name",
+        ));
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            r#"# my package
+[package]
+name = "foo"
+version = "0.2.0" # bump me
+
+[tool.foo]
+bar = 42
+"#
+        );
+    }
+}
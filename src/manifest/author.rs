@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 pub use email_address::{EmailAddress, Error as ParseEmailError};
@@ -7,6 +8,7 @@ use serde::{
     Deserialize, Serialize,
 };
 use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 use unscanny::Scanner;
 
 pub use self::github_handle::{GitHubHandle, ParseGitHubHandleError};
@@ -14,15 +16,68 @@ pub use super::website::{ParseWebsiteError, Website};
 
 pub mod github_handle;
 
+/// A package author with an optional contact, given in the manifest as
+/// `"name"` or `"name <contact>"`. Two authors are equal if both their names
+/// and contacts are equal, email domains are compared case-insensitively.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Author {
     pub name: String,
     pub contact: Option<Contact>,
 }
 
+impl Author {
+    /// Creates an author without a contact, the name is trimmed. An empty
+    /// name is allowed here, but is rejected by [`Manifest::validate`] with
+    /// [`ValidationError::EmptyAuthorName`].
+    ///
+    /// [`Manifest::validate`]: super::Manifest::validate
+    /// [`ValidationError::EmptyAuthorName`]: super::validation::ValidationError::EmptyAuthorName
+    pub fn from_name(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            name: name.trim().to_owned(),
+            contact: None,
+        }
+    }
+
+    /// Returns this author without their contact.
+    pub fn without_contact(self) -> Author {
+        Author {
+            name: self.name,
+            contact: None,
+        }
+    }
+
+    /// Returns only the name of this author.
+    pub fn name_only(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of this author in NFC form with leading and trailing
+    /// whitespace removed and consecutive whitespace collapsed into a single
+    /// space.
+    pub fn name_normalized(&self) -> String {
+        self.name
+            .nfc()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns this author with their name normalized, see
+    /// [`Author::name_normalized`].
+    pub fn normalize(self) -> Author {
+        Author {
+            name: self.name_normalized(),
+            contact: self.contact,
+        }
+    }
+}
+
 impl Display for Author {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")?;
+        write!(f, "{}", self.name)?;
         if let Some(contact) = &self.contact {
             match contact {
                 Contact::GitHubHandle(handle) => write!(f, " <@{handle}>"),
@@ -89,13 +144,195 @@ impl FromStr for Author {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Contact {
     GitHubHandle(GitHubHandle),
     Website(Website),
     Email(EmailAddress),
 }
 
+/// The kind of contact to parse with [`Contact::parse_with_hint`]. GitLab
+/// users and ORCID iDs have no contact of their own and are turned into
+/// websites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContactKind {
+    /// A GitHub handle with an optional leading `@`.
+    GitHub,
+
+    /// A GitLab username with an optional leading `@`.
+    GitLab,
+
+    /// A website url.
+    Website,
+
+    /// An email address.
+    Email,
+
+    /// An ORCID iD such as `0000-0002-1825-0097`, optionally given as an
+    /// `https://orcid.org/` url.
+    Orcid,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ParseContactError {
+    #[error("invalid email address")]
+    InvalidEmailAddress(#[from] ParseEmailError),
+
+    #[error("invalid github handle")]
+    InvalidGithubHandle(#[from] ParseGitHubHandleError),
+
+    #[error("invalid website")]
+    InvalidWebsite(#[from] ParseWebsiteError),
+
+    #[error("invalid gitlab username")]
+    InvalidGitLabUser,
+
+    #[error("invalid orcid")]
+    InvalidOrcid,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Author {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let letters = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        let rest = [letters.as_slice(), b" .-'"].concat();
+
+        Ok(Self {
+            name: crate::utils::arbitrary_str(u, letters, &rest, 24)?
+                .trim()
+                .to_owned(),
+            contact: u.arbitrary()?,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Contact {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utils::{arbitrary_str, LOWER_ALNUM};
+
+        Ok(match u.int_in_range(0..=2)? {
+            0 => Contact::GitHubHandle(u.arbitrary()?),
+            1 => Contact::Website(u.arbitrary()?),
+            _ => {
+                let email = format!(
+                    "{}@{}.com",
+                    arbitrary_str(u, LOWER_ALNUM, LOWER_ALNUM, 16)?,
+                    arbitrary_str(u, LOWER_ALNUM, LOWER_ALNUM, 16)?,
+                );
+                Contact::Email(
+                    email
+                        .parse()
+                        .map_err(|_| arbitrary::Error::IncorrectFormat)?,
+                )
+            }
+        })
+    }
+}
+
+impl Contact {
+    /// Parses a contact of the given kind, unlike [`Author::from_str`] this
+    /// does not guess the kind of contact from `s`.
+    ///
+    /// Returns an error if `s` is not a valid contact of the given kind.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::author::{Contact, ContactKind};
+    ///
+    /// let contact = Contact::parse_with_hint(ContactKind::GitLab, "@tingerrr")?;
+    /// assert!(matches!(contact, Contact::Website(w) if w == "https://gitlab.com/tingerrr"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_with_hint(kind: ContactKind, s: &str) -> Result<Contact, ParseContactError> {
+        Ok(match kind {
+            ContactKind::GitHub => Contact::GitHubHandle(s.strip_prefix('@').unwrap_or(s).parse()?),
+            ContactKind::GitLab => {
+                let user = s.strip_prefix('@').unwrap_or(s);
+                if user.is_empty()
+                    || !user
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "_-.".contains(c))
+                {
+                    return Err(ParseContactError::InvalidGitLabUser);
+                }
+
+                Contact::Website(format!("https://gitlab.com/{user}").parse()?)
+            }
+            ContactKind::Website => Contact::Website(s.parse()?),
+            ContactKind::Email => Contact::Email(s.parse()?),
+            ContactKind::Orcid => {
+                let id = s.strip_prefix("https://orcid.org/").unwrap_or(s);
+                if !is_valid_orcid(id) {
+                    return Err(ParseContactError::InvalidOrcid);
+                }
+
+                Contact::Website(format!("https://orcid.org/{id}").parse()?)
+            }
+        })
+    }
+
+    /// Compares two contacts ignoring case where it is insignificant in
+    /// practice. GitHub handles and emails are compared case-insensitively,
+    /// websites are compared by their normalized url.
+    pub fn eq_ignore_case(&self, other: &Contact) -> bool {
+        match (self, other) {
+            (Contact::GitHubHandle(this), Contact::GitHubHandle(other)) => {
+                this.eq_ignore_ascii_case(other)
+            }
+            (Contact::Website(this), Contact::Website(other)) => {
+                this.clone().normalize() == other.clone().normalize()
+            }
+            (Contact::Email(this), Contact::Email(other)) => {
+                this.local_part().to_lowercase() == other.local_part().to_lowercase()
+                    && this.domain().eq_ignore_ascii_case(other.domain())
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Checks the format and ISO 7064 11,2 check digit of an ORCID iD.
+fn is_valid_orcid(id: &str) -> bool {
+    let groups: Vec<_> = id.split('-').collect();
+    if groups.len() != 4 || groups.iter().any(|g| g.len() != 4) {
+        return false;
+    }
+
+    let digits: Vec<_> = groups.concat().chars().collect();
+    let (check, digits) = digits.split_last().expect("orcid has 16 characters");
+
+    let mut total = 0;
+    for digit in digits {
+        let Some(digit) = digit.to_digit(10) else {
+            return false;
+        };
+        total = (total + digit) * 2;
+    }
+
+    let expected = (12 - total % 11) % 11;
+    match check {
+        'X' => expected == 10,
+        c => c.to_digit(10) == Some(expected),
+    }
+}
+
+impl Hash for Contact {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Contact::GitHubHandle(handle) => handle.hash(state),
+            Contact::Website(website) => website.hash(state),
+            // NOTE: EmailAddress compares domains case-insensitively but hashes
+            // them as is, so we hash it consistently with its PartialEq impl
+            Contact::Email(email) => {
+                email.local_part().hash(state);
+                email.domain().to_ascii_lowercase().hash(state);
+            }
+        }
+    }
+}
+
 impl Serialize for Author {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -135,9 +372,23 @@ impl<'de> Deserialize<'de> for Author {
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
     use crate::{assert_err, assert_ok};
 
+    #[test]
+    fn display() {
+        let author = Author::from_str("John Doe").unwrap();
+        assert_eq!(author.to_string(), "John Doe");
+
+        let author = Author::from_str("John Doe <john@doe.com>").unwrap();
+        assert_eq!(author.to_string(), "John Doe <john@doe.com>");
+
+        let author = Author::from_str("Martin <@reknih>").unwrap();
+        assert_eq!(author.to_string(), "Martin <@reknih>");
+    }
+
     #[test]
     fn invalid() {
         assert_err!(
@@ -162,6 +413,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eq_ignore_case() {
+        fn contact(s: &str) -> Contact {
+            Author::from_str(s).unwrap().contact.unwrap()
+        }
+
+        assert!(contact("<@reknih>").eq_ignore_case(&contact("<@Reknih>")));
+        assert!(contact("<Martin@TYPST.app>").eq_ignore_case(&contact("<martin@typst.app>")));
+        assert!(contact("<https://MHA.ug>").eq_ignore_case(&contact("<https://mha.ug/>")));
+        assert!(contact("<https://mha.ug/foo/>").eq_ignore_case(&contact("<https://mha.ug/foo>")));
+        assert!(!contact("<@reknih>").eq_ignore_case(&contact("<@martin>")));
+        assert!(!contact("<https://mha.ug>").eq_ignore_case(&contact("<martin@mha.ug>")));
+    }
+
+    #[test]
+    fn hash_eq() {
+        use std::collections::HashSet;
+
+        let authors: HashSet<_> = [
+            "Martin <@reknih>",
+            "Martin  <@reknih>",
+            "Martin",
+            "Martin <martin@typst.app>",
+            "Martin <martin@TYPST.app>",
+        ]
+        .into_iter()
+        .map(|a| Author::from_str(a).unwrap())
+        .collect();
+
+        assert_eq!(authors.len(), 3);
+    }
+
+    #[test]
+    fn parse_with_hint() {
+        fn website(kind: ContactKind, s: &str) -> Option<String> {
+            match Contact::parse_with_hint(kind, s) {
+                Ok(Contact::Website(website)) => Some(website.to_string()),
+                _ => None,
+            }
+        }
+
+        assert!(matches!(
+            Contact::parse_with_hint(ContactKind::GitHub, "@reknih"),
+            Ok(Contact::GitHubHandle(h)) if h == "reknih"
+        ));
+        assert!(matches!(
+            Contact::parse_with_hint(ContactKind::Email, "john@doe.com"),
+            Ok(Contact::Email(_))
+        ));
+        assert_eq!(
+            website(ContactKind::GitLab, "tingerrr"),
+            Some("https://gitlab.com/tingerrr".into())
+        );
+        assert_eq!(
+            website(ContactKind::Orcid, "https://orcid.org/0000-0002-1694-233X"),
+            Some("https://orcid.org/0000-0002-1694-233X".into())
+        );
+        assert_eq!(
+            website(ContactKind::Orcid, "0000-0002-1825-0097"),
+            Some("https://orcid.org/0000-0002-1825-0097".into())
+        );
+
+        assert_eq!(
+            Contact::parse_with_hint(ContactKind::GitLab, "foo/bar"),
+            Err(ParseContactError::InvalidGitLabUser)
+        );
+        assert_eq!(
+            Contact::parse_with_hint(ContactKind::Orcid, "0000-0002-1825-0098"),
+            Err(ParseContactError::InvalidOrcid)
+        );
+        assert!(Contact::parse_with_hint(ContactKind::Email, "@reknih").is_err());
+        assert!(Contact::parse_with_hint(ContactKind::Website, "john@doe.com").is_err());
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(
+            Author::from_name(" Martin "),
+            Author::from_str("Martin").unwrap()
+        );
+        assert_eq!(Author::from_name("").name, "");
+    }
+
+    #[test]
+    fn name_normalized() {
+        let author = Author {
+            name: " John \t Doe  ".into(),
+            contact: None,
+        };
+        assert_eq!(author.name_normalized(), "John Doe");
+
+        // decomposed `ä` is composed
+        let author = Author {
+            name: "Ba\u{308}r".into(),
+            contact: None,
+        };
+        assert_eq!(author.normalize().name, "B\u{e4}r");
+    }
+
     #[test]
     fn valid() {
         assert_ok!(
@@ -199,4 +549,20 @@ mod tests {
             },
         );
     }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip(
+            s in "[a-zA-Z ]{0,12}( <(@[a-zA-Z0-9-]{1,16}|https://[a-z]{1,8}\\.[a-z]{2,3}(/[a-z]{0,5})?|[a-z.]{1,8}@[a-z]{1,8}\\.[a-z]{2,3})>)?|\\PC{0,24}"
+        ) {
+            if let Ok(author) = Author::from_str(&s) {
+                prop_assert_eq!(Author::from_str(&author.to_string()), Ok(author));
+            }
+        }
+
+        #[test]
+        fn prop_invalid_unclosed(s in "[a-zA-Z ]{0,12} <@[a-z]{1,16}") {
+            prop_assert_eq!(Author::from_str(&s), Err(ParseAuthorError::UnclosedContact));
+        }
+    }
 }
@@ -1,9 +1,21 @@
 use thiserror::Error;
 use unscanny::Scanner;
 
-use crate::{define_conversions, define_formatting, define_serde};
+use crate::{define_newtype, define_str_eq};
+
+/// Checks whether `s` is a valid GitHub handle without constructing a
+/// [`GitHubHandle`], `s` must not contain the leading `@`.
+///
+/// Returns an error if `s` is not a valid GitHub handle.
+pub fn validate_github_handle(s: &str) -> Result<(), ParseGitHubHandleError> {
+    is_valid_github_handle(s)
+}
 
 fn is_valid_github_handle(s: &str) -> Result<(), ParseGitHubHandleError> {
+    if s.is_empty() {
+        return Err(ParseGitHubHandleError::Empty);
+    }
+
     if s.len() > 39 {
         return Err(ParseGitHubHandleError::TooLong);
     }
@@ -37,11 +49,68 @@ fn is_valid_github_handle(s: &str) -> Result<(), ParseGitHubHandleError> {
     Ok(())
 }
 
+/// The Levenshtein distance between two strings, ignoring ASCII case.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let mut row: Vec<_> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().map(|c| c.to_ascii_lowercase()).enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diag + usize::from(ca != cb))
+                .min(above + 1)
+                .min(row[j] + 1);
+            diag = above;
+        }
+    }
+
+    row[b.len()]
+}
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GitHubHandle(String);
 
+impl GitHubHandle {
+    /// Checks whether `s` is a valid GitHub handle without constructing a
+    /// [`GitHubHandle`], this is the same as [`validate_github_handle`].
+    ///
+    /// Returns an error describing the first problem with `s`.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::author::{GitHubHandle, ParseGitHubHandleError};
+    ///
+    /// assert!(GitHubHandle::validate_format("reknih").is_ok());
+    /// assert_eq!(
+    ///     GitHubHandle::validate_format("re_knih"),
+    ///     Err(ParseGitHubHandleError::ContainsInvalidChar('_')),
+    /// );
+    /// ```
+    pub fn validate_format(s: &str) -> Result<(), ParseGitHubHandleError> {
+        is_valid_github_handle(s)
+    }
+
+    /// Returns up to three of the given candidates which are most similar to
+    /// `s` by edit distance, the closest candidate comes first. This is useful
+    /// for suggesting corrections for mistyped handles.
+    pub fn suggest_similar<'a>(
+        s: &str,
+        candidates: impl Iterator<Item = &'a GitHubHandle>,
+    ) -> Vec<&'a GitHubHandle> {
+        let mut candidates: Vec<_> = candidates.map(|c| (edit_distance(s, c), c)).collect();
+        candidates.sort_by_key(|&(distance, _)| distance);
+        candidates.into_iter().take(3).map(|(_, c)| c).collect()
+    }
+}
+
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum ParseGitHubHandleError {
+    #[error("handle must not be empty")]
+    Empty,
+
     #[error("handle must not be longer than 39 characters")]
     TooLong,
 
@@ -58,19 +127,44 @@ pub enum ParseGitHubHandleError {
     ContainsConsecutiveHyphens,
 }
 
-define_formatting!(GitHubHandle);
-define_conversions!(GitHubHandle, ParseGitHubHandleError, is_valid_github_handle);
-define_serde!(
-    GitHubHandle,
+define_newtype!(
+    prefixed GitHubHandle,
     ParseGitHubHandleError,
     is_valid_github_handle,
     "a github handle"
 );
+define_str_eq!(GitHubHandle);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GitHubHandle {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utils::{arbitrary_str, LOWER_ALNUM};
+
+        // at most 4 segments of 8 characters and 3 hyphens stay below 39
+        let segments = (0..u.int_in_range(1..=4)?)
+            .map(|_| arbitrary_str(u, LOWER_ALNUM, LOWER_ALNUM, 8))
+            .collect::<arbitrary::Result<Vec<_>>>()?;
+        segments
+            .join("-")
+            .parse()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
+    #[test]
+    fn invalid_empty() {
+        assert_eq!(
+            is_valid_github_handle(""),
+            Err(ParseGitHubHandleError::Empty)
+        );
+    }
+
     #[test]
     fn invalid_hyphens() {
         assert_eq!(
@@ -103,9 +197,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn edit_distance() {
+        assert_eq!(super::edit_distance("reknih", "reknih"), 0);
+        assert_eq!(super::edit_distance("reknih", "Reknih"), 0);
+        assert_eq!(super::edit_distance("reknih", "rekni"), 1);
+        assert_eq!(super::edit_distance("kitten", "sitting"), 3);
+        assert_eq!(super::edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn suggest_similar() {
+        let handles: Vec<GitHubHandle> = ["tingerrr", "reknih", "laurmaedje", "reknil", "foo"]
+            .into_iter()
+            .map(|h| h.parse().unwrap())
+            .collect();
+
+        let suggestions = GitHubHandle::suggest_similar("reknh", handles.iter());
+        assert_eq!(
+            suggestions
+                .into_iter()
+                .map(|h| h.as_ref())
+                .collect::<Vec<&str>>(),
+            ["reknih", "reknil", "foo"]
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        let handle: GitHubHandle = "reknih".parse().unwrap();
+        assert_eq!(handle.to_string(), "reknih");
+        assert_eq!(format!("{handle:?}"), r#"GitHubHandle("reknih")"#);
+    }
+
     #[test]
     fn valid() {
         assert!(is_valid_github_handle("reknih").is_ok());
         assert!(is_valid_github_handle("tingerrr").is_ok());
     }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip(s in "[a-zA-Z0-9-]{0,40}|\\PC{0,16}") {
+            if let Ok(handle) = s.parse::<GitHubHandle>() {
+                prop_assert_eq!(handle.to_string().parse(), Ok(handle));
+            }
+        }
+
+        #[test]
+        fn prop_too_long(s in "[a-zA-Z0-9]{40,64}") {
+            prop_assert_eq!(
+                is_valid_github_handle(&s),
+                Err(ParseGitHubHandleError::TooLong)
+            );
+        }
+
+        #[test]
+        fn prop_invalid_char(s in "[a-z]{1,8}[_.@ ][a-z]{1,8}") {
+            prop_assert!(matches!(
+                is_valid_github_handle(&s),
+                Err(ParseGitHubHandleError::ContainsInvalidChar(_))
+            ));
+        }
+    }
 }
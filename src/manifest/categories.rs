@@ -1,7 +1,13 @@
 //! Typst package categories.
 
+use std::cmp::Ordering;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 use strum::{EnumString, IntoStaticStr};
+use thiserror::Error;
+
+use super::disciplines::Discipline;
 
 // taken from:
 // https://github.com/typst/packages/blob/aac865d4463dd00d7bafc05f31362db27b054309/CATEGORIES.md
@@ -11,6 +17,7 @@ use strum::{EnumString, IntoStaticStr};
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, IntoStaticStr,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum Category {
@@ -88,8 +95,11 @@ pub enum Category {
 }
 
 impl Category {
+    /// The number of variants of [Category].
+    pub const COUNT: usize = 19;
+
     /// An ordered array of all variants of [Category].
-    pub const ALL: [Self; 19] = [
+    pub const ALL: [Self; Self::COUNT] = [
         Self::Book,
         Self::Components,
         Self::Cv,
@@ -139,8 +149,339 @@ impl Category {
         Self::Thesis,
     ];
 
+    /// An array of all variants of [Category], guaranteed to be sorted
+    /// alphabetically by their kebab-case text representation. This order is
+    /// stable and suitable for generating listings or autocompletion.
+    pub const ALL_SORTED: [Self; Self::COUNT] = Self::ALL;
+
     /// Converts a [Category] into it's kebab-case text representation.
     pub fn to_str(self) -> &'static str {
         self.into()
     }
+
+    /// Returns the key this category is serialized as, such as
+    /// `"visualization"` in the `categories` key of a manifest. This is
+    /// guaranteed to match the serialized form and is currently the same as
+    /// [Category::to_str].
+    pub fn to_serde_key(self) -> &'static str {
+        self.to_str()
+    }
+
+    /// Returns the short abbreviation of this category, these are given in
+    /// the following table:
+    ///
+    /// | Name            | Abbreviation |
+    /// |-----------------|--------------|
+    /// | `book`          | `book`       |
+    /// | `components`    | `comp`       |
+    /// | `cv`            | `cv`         |
+    /// | `flyer`         | `fly`        |
+    /// | `fun`           | `fun`        |
+    /// | `integration`   | `int`        |
+    /// | `languages`     | `lang`       |
+    /// | `layout`        | `lay`        |
+    /// | `model`         | `mod`        |
+    /// | `office`        | `off`        |
+    /// | `paper`         | `pap`        |
+    /// | `poster`        | `post`       |
+    /// | `presentation`  | `pres`       |
+    /// | `report`        | `rep`        |
+    /// | `scripting`     | `scr`        |
+    /// | `text`          | `txt`        |
+    /// | `thesis`        | `thes`       |
+    /// | `utility`       | `util`       |
+    /// | `visualization` | `viz`        |
+    pub fn abbrev(self) -> &'static str {
+        match self {
+            Self::Book => "book",
+            Self::Components => "comp",
+            Self::Cv => "cv",
+            Self::Flyer => "fly",
+            Self::Fun => "fun",
+            Self::Integration => "int",
+            Self::Languages => "lang",
+            Self::Layout => "lay",
+            Self::Model => "mod",
+            Self::Office => "off",
+            Self::Paper => "pap",
+            Self::Poster => "post",
+            Self::Presentation => "pres",
+            Self::Report => "rep",
+            Self::Scripting => "scr",
+            Self::Text => "txt",
+            Self::Thesis => "thes",
+            Self::Utility => "util",
+            Self::Visualization => "viz",
+        }
+    }
+
+    /// Parses a category from its abbreviation, see [Category::abbrev] for the
+    /// table of abbreviations.
+    ///
+    /// Returns `None` if `s` is not a known abbreviation.
+    pub fn from_abbrev(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|v| v.abbrev() == s)
+    }
+
+    /// A link to the section of the typst packages `CATEGORIES.md` file
+    /// describing the [functional categories][Category::FUNCTIONAL]. The link
+    /// points to the revision the categories were taken from.
+    pub const FUNCTIONAL_DOCUMENTATION_URL: &'static str = "https://github.com/typst/packages/blob/aac865d4463dd00d7bafc05f31362db27b054309/CATEGORIES.md#functional-categories";
+
+    /// A link to the section of the typst packages `CATEGORIES.md` file
+    /// describing the [publication categories][Category::PUBLICATION]. The
+    /// link points to the revision the categories were taken from.
+    pub const PUBLICATION_DOCUMENTATION_URL: &'static str = "https://github.com/typst/packages/blob/aac865d4463dd00d7bafc05f31362db27b054309/CATEGORIES.md#publication-categories";
+
+    /// Returns a link to the section of the typst packages `CATEGORIES.md`
+    /// file listing this category. The file has no per-category anchors, so
+    /// this is either [`Category::FUNCTIONAL_DOCUMENTATION_URL`] or
+    /// [`Category::PUBLICATION_DOCUMENTATION_URL`], shared by all categories
+    /// of the same kind.
+    pub fn documentation_url(self) -> &'static str {
+        if Self::FUNCTIONAL.contains(&self) {
+            Self::FUNCTIONAL_DOCUMENTATION_URL
+        } else {
+            Self::PUBLICATION_DOCUMENTATION_URL
+        }
+    }
+
+    /// Returns a curated list of disciplines which commonly pair with this
+    /// category, sorted like [Discipline::ALL]. This is meant for suggesting
+    /// disciplines and is empty for categories which are not tied to any
+    /// discipline, such as [Category::Utility].
+    pub fn related_disciplines(self) -> &'static [Discipline] {
+        use Discipline as D;
+
+        match self {
+            Self::Book => &[D::History, D::Literature, D::Philosophy, D::Theology],
+            Self::Components => &[],
+            Self::Cv => &[D::Business, D::Design],
+            Self::Flyer => &[D::Business, D::Communication, D::Design],
+            Self::Fun => &[],
+            Self::Integration => &[D::ComputerScience],
+            Self::Languages => &[D::Linguistics, D::Literature],
+            Self::Layout => &[D::Design],
+            Self::Model => &[D::Education, D::Law, D::Literature],
+            Self::Office => &[D::Business, D::Law],
+            Self::Paper => &[
+                D::Biology,
+                D::Chemistry,
+                D::ComputerScience,
+                D::Economics,
+                D::Engineering,
+                D::Mathematics,
+                D::Medicine,
+                D::Physics,
+                D::Psychology,
+                D::Sociology,
+            ],
+            Self::Poster => &[
+                D::Biology,
+                D::Chemistry,
+                D::Design,
+                D::Education,
+                D::Medicine,
+                D::Physics,
+            ],
+            Self::Presentation => &[
+                D::Business,
+                D::Communication,
+                D::ComputerScience,
+                D::Education,
+                D::Engineering,
+                D::Mathematics,
+                D::Physics,
+            ],
+            Self::Report => &[
+                D::Business,
+                D::ComputerScience,
+                D::Economics,
+                D::Engineering,
+                D::Journalism,
+                D::Politics,
+            ],
+            Self::Scripting => &[D::ComputerScience],
+            Self::Text => &[D::Linguistics, D::Literature],
+            Self::Thesis => &[
+                D::Anthropology,
+                D::Archaeology,
+                D::Biology,
+                D::Chemistry,
+                D::ComputerScience,
+                D::Economics,
+                D::Education,
+                D::Engineering,
+                D::History,
+                D::Law,
+                D::Linguistics,
+                D::Mathematics,
+                D::Medicine,
+                D::Philosophy,
+                D::Physics,
+                D::Psychology,
+                D::Sociology,
+            ],
+            Self::Utility => &[],
+            Self::Visualization => &[
+                D::Biology,
+                D::ComputerScience,
+                D::Economics,
+                D::Engineering,
+                D::Geography,
+                D::Mathematics,
+                D::Physics,
+            ],
+        }
+    }
+
+    /// Parses a [Category] ignoring case and treating spaces as hyphens, such
+    /// that `"Components"` or `"COMPONENTS"` are parsed like `"components"`.
+    ///
+    /// Returns `None` if `s` is not a valid category.
+    pub fn try_from_str_case_insensitive(s: &str) -> Option<Self> {
+        Self::from_str(&s.trim().to_lowercase().replace(' ', "-")).ok()
+    }
+
+    /// Parses a comma separated list of categories, such as `"text, layout"`.
+    /// Each item is trimmed before parsing.
+    ///
+    /// Returns an error for the first item which is not a valid category.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::categories::Category;
+    ///
+    /// assert_eq!(
+    ///     Category::parse_multiple("text, layout")?,
+    ///     [Category::Text, Category::Layout],
+    /// );
+    /// assert!(Category::parse_multiple("text,foo").is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_multiple(s: &str) -> Result<Vec<Self>, ParseCategoryError> {
+        s.split(',')
+            .map(str::trim)
+            .map(|item| Self::from_str(item).map_err(|_| ParseCategoryError(item.to_owned())))
+            .collect()
+    }
+}
+
+/// An error that can occur when parsing a [Category] from a list of categories.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown category `{0}`")]
+pub struct ParseCategoryError(pub String);
+
+impl Category {
+    /// The index of this variant in [Category::ALL].
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&v| v == self)
+            .expect("all variants are in ALL")
+    }
+}
+
+/// Categories are ordered by their position in [Category::ALL].
+impl PartialOrd for Category {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Category {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index().cmp(&other.index())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_sorted() {
+        assert!(Category::ALL_SORTED
+            .windows(2)
+            .all(|w| w[0].to_str() < w[1].to_str()));
+    }
+
+    #[test]
+    fn related_disciplines() {
+        for v in Category::ALL {
+            assert!(v.related_disciplines().windows(2).all(|w| w[0] < w[1]));
+        }
+
+        assert!(Category::Thesis
+            .related_disciplines()
+            .contains(&Discipline::Mathematics));
+        assert!(Category::Utility.related_disciplines().is_empty());
+    }
+
+    #[test]
+    fn documentation_url() {
+        for v in Category::FUNCTIONAL {
+            assert_eq!(
+                v.documentation_url(),
+                Category::FUNCTIONAL_DOCUMENTATION_URL
+            );
+        }
+        for v in Category::PUBLICATION {
+            assert_eq!(
+                v.documentation_url(),
+                Category::PUBLICATION_DOCUMENTATION_URL
+            );
+        }
+        assert!(Category::FUNCTIONAL_DOCUMENTATION_URL.ends_with("#functional-categories"));
+        assert!(Category::PUBLICATION_DOCUMENTATION_URL.ends_with("#publication-categories"));
+    }
+
+    #[test]
+    fn serde_key() {
+        for v in Category::ALL {
+            assert_eq!(
+                toml::Value::try_from(v).unwrap().as_str(),
+                Some(v.to_serde_key())
+            );
+        }
+    }
+
+    #[test]
+    fn abbrev() {
+        for v in Category::ALL {
+            assert_eq!(Category::from_abbrev(v.abbrev()), Some(v));
+        }
+        assert_eq!(Category::from_abbrev("foo"), None);
+    }
+
+    #[test]
+    fn ord() {
+        assert!(Category::ALL.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn parse_multiple() {
+        assert_eq!(
+            Category::parse_multiple(" text ,layout").unwrap(),
+            [Category::Text, Category::Layout]
+        );
+        assert_eq!(
+            Category::parse_multiple("text, foo, bar").unwrap_err(),
+            ParseCategoryError("foo".into())
+        );
+        assert!(Category::parse_multiple("").is_err());
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(
+            Category::try_from_str_case_insensitive("Components"),
+            Some(Category::Components)
+        );
+        assert_eq!(
+            Category::try_from_str_case_insensitive("CV"),
+            Some(Category::Cv)
+        );
+        assert_eq!(Category::try_from_str_case_insensitive("Compnents"), None);
+    }
 }
@@ -1,7 +1,11 @@
 //! Typst package disciplines.
 
+use std::cmp::Ordering;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 use strum::{EnumString, IntoStaticStr};
+use thiserror::Error;
 
 // taken from:
 // https://github.com/typst/packages/blob/aac865d4463dd00d7bafc05f31362db27b054309/DISCIPLINES.md
@@ -11,6 +15,7 @@ use strum::{EnumString, IntoStaticStr};
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, IntoStaticStr,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum Discipline {
@@ -53,8 +58,11 @@ pub enum Discipline {
 }
 
 impl Discipline {
+    /// The number of variants of [Discipline].
+    pub const COUNT: usize = 36;
+
     /// An ordered array of all variants of [Discipline].
-    pub const ALL: [Self; 36] = [
+    pub const ALL: [Self; Self::COUNT] = [
         Self::Agriculture,
         Self::Anthropology,
         Self::Archaeology,
@@ -93,8 +101,211 @@ impl Discipline {
         Self::Transportation,
     ];
 
+    /// An array of all variants of [Discipline], guaranteed to be sorted
+    /// alphabetically by their kebab-case text representation. This order is
+    /// stable and suitable for generating listings or autocompletion.
+    pub const ALL_SORTED: [Self; Self::COUNT] = Self::ALL;
+
+    /// An iterator over all variants of [Discipline] sorted alphabetically by
+    /// their kebab-case text representation, see [Discipline::ALL_SORTED].
+    pub fn iter_alphabetical() -> impl Iterator<Item = Discipline> {
+        Self::ALL_SORTED.into_iter()
+    }
+
     /// Converts a [Discipline] into it's kebab-case text representation.
     pub fn to_str(self) -> &'static str {
         self.into()
     }
+
+    /// Returns the short abbreviation of this discipline, these are given in
+    /// the following table:
+    ///
+    /// | Name               | Abbreviation |
+    /// |--------------------|--------------|
+    /// | `agriculture`      | `agri`       |
+    /// | `anthropology`     | `anth`       |
+    /// | `archaeology`      | `arch`       |
+    /// | `architecture`     | `archi`      |
+    /// | `biology`          | `bio`        |
+    /// | `business`         | `bus`        |
+    /// | `chemistry`        | `chem`       |
+    /// | `communication`    | `comm`       |
+    /// | `computer-science` | `cs`         |
+    /// | `design`           | `des`        |
+    /// | `drawing`          | `draw`       |
+    /// | `economics`        | `econ`       |
+    /// | `education`        | `edu`        |
+    /// | `engineering`      | `eng`        |
+    /// | `fashion`          | `fash`       |
+    /// | `film`             | `film`       |
+    /// | `geography`        | `geog`       |
+    /// | `geology`          | `geol`       |
+    /// | `history`          | `hist`       |
+    /// | `journalism`       | `jour`       |
+    /// | `law`              | `law`        |
+    /// | `linguistics`      | `ling`       |
+    /// | `literature`       | `lit`        |
+    /// | `mathematics`      | `math`       |
+    /// | `medicine`         | `med`        |
+    /// | `music`            | `mus`        |
+    /// | `painting`         | `paint`      |
+    /// | `philosophy`       | `phil`       |
+    /// | `photography`      | `photo`      |
+    /// | `physics`          | `phys`       |
+    /// | `politics`         | `pol`        |
+    /// | `psychology`       | `psych`      |
+    /// | `sociology`        | `soc`        |
+    /// | `theater`          | `thea`       |
+    /// | `theology`         | `theo`       |
+    /// | `transportation`   | `trans`      |
+    pub fn abbrev(self) -> &'static str {
+        match self {
+            Self::Agriculture => "agri",
+            Self::Anthropology => "anth",
+            Self::Archaeology => "arch",
+            Self::Architecture => "archi",
+            Self::Biology => "bio",
+            Self::Business => "bus",
+            Self::Chemistry => "chem",
+            Self::Communication => "comm",
+            Self::ComputerScience => "cs",
+            Self::Design => "des",
+            Self::Drawing => "draw",
+            Self::Economics => "econ",
+            Self::Education => "edu",
+            Self::Engineering => "eng",
+            Self::Fashion => "fash",
+            Self::Film => "film",
+            Self::Geography => "geog",
+            Self::Geology => "geol",
+            Self::History => "hist",
+            Self::Journalism => "jour",
+            Self::Law => "law",
+            Self::Linguistics => "ling",
+            Self::Literature => "lit",
+            Self::Mathematics => "math",
+            Self::Medicine => "med",
+            Self::Music => "mus",
+            Self::Painting => "paint",
+            Self::Philosophy => "phil",
+            Self::Photography => "photo",
+            Self::Physics => "phys",
+            Self::Politics => "pol",
+            Self::Psychology => "psych",
+            Self::Sociology => "soc",
+            Self::Theater => "thea",
+            Self::Theology => "theo",
+            Self::Transportation => "trans",
+        }
+    }
+
+    /// A link to the typst packages `DISCIPLINES.md` file listing all
+    /// disciplines. The file has no per-discipline anchors, so this is one
+    /// link shared by all disciplines. The link points to the revision the
+    /// disciplines were taken from.
+    pub const DOCUMENTATION_URL: &'static str = "https://github.com/typst/packages/blob/aac865d4463dd00d7bafc05f31362db27b054309/DISCIPLINES.md";
+
+    /// Parses a discipline from its abbreviation, see [Discipline::abbrev] for the
+    /// table of abbreviations.
+    ///
+    /// Returns `None` if `s` is not a known abbreviation.
+    pub fn from_abbrev(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|v| v.abbrev() == s)
+    }
+
+    /// Parses a comma separated list of disciplines, such as
+    /// `"physics, mathematics"`. Each item is trimmed before parsing.
+    ///
+    /// Returns an error for the first item which is not a valid discipline.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::disciplines::Discipline;
+    ///
+    /// assert_eq!(
+    ///     Discipline::parse_multiple("physics, mathematics")?,
+    ///     [Discipline::Physics, Discipline::Mathematics],
+    /// );
+    /// assert!(Discipline::parse_multiple("physics,foo").is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_multiple(s: &str) -> Result<Vec<Self>, ParseDisciplineError> {
+        s.split(',')
+            .map(str::trim)
+            .map(|item| Self::from_str(item).map_err(|_| ParseDisciplineError(item.to_owned())))
+            .collect()
+    }
+}
+
+/// An error that can occur when parsing a [Discipline] from a list of
+/// disciplines.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("unknown discipline `{0}`")]
+pub struct ParseDisciplineError(pub String);
+
+impl Discipline {
+    /// The index of this variant in [Discipline::ALL].
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&v| v == self)
+            .expect("all variants are in ALL")
+    }
+}
+
+/// Disciplines are ordered by their position in [Discipline::ALL].
+impl PartialOrd for Discipline {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Discipline {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index().cmp(&other.index())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_sorted() {
+        assert!(Discipline::ALL_SORTED
+            .windows(2)
+            .all(|w| w[0].to_str() < w[1].to_str()));
+    }
+
+    #[test]
+    fn iter_alphabetical() {
+        let mut sorted = Discipline::ALL;
+        sorted.sort_by_key(|v| v.to_str());
+        assert!(Discipline::iter_alphabetical().eq(sorted));
+    }
+
+    #[test]
+    fn abbrev() {
+        for v in Discipline::ALL {
+            assert_eq!(Discipline::from_abbrev(v.abbrev()), Some(v));
+        }
+        assert_eq!(Discipline::from_abbrev("foo"), None);
+    }
+
+    #[test]
+    fn parse_multiple() {
+        assert_eq!(
+            Discipline::parse_multiple(" physics ,mathematics").unwrap(),
+            [Discipline::Physics, Discipline::Mathematics]
+        );
+        assert_eq!(
+            Discipline::parse_multiple("physics, foo, bar").unwrap_err(),
+            ParseDisciplineError("foo".into())
+        );
+    }
+
+    #[test]
+    fn ord() {
+        assert!(Discipline::ALL.windows(2).all(|w| w[0] < w[1]));
+    }
 }
@@ -1,8 +1,19 @@
 use unicode_ident::{is_xid_continue, is_xid_start};
 
-use crate::{define_conversions, define_formatting, define_serde};
+use crate::{define_newtype, define_str_eq};
 
-fn is_valid_ident(s: &str) -> Result<(), ParseIdentError> {
+/// Checks whether `s` is a valid identifier without constructing an [`Ident`].
+///
+/// Returns an error if `s` is not a valid identifier.
+///
+/// # Examples
+/// ```
+/// use typst_project::manifest::ident::is_valid_ident;
+///
+/// assert!(is_valid_ident("my-package").is_ok());
+/// assert!(is_valid_ident("1st-package").is_err());
+/// ```
+pub fn is_valid_ident(s: &str) -> Result<(), ParseIdentError> {
     fn is_id_start(c: char) -> bool {
         is_xid_start(c) || c == '_'
     }
@@ -11,6 +22,10 @@ fn is_valid_ident(s: &str) -> Result<(), ParseIdentError> {
         is_xid_continue(c) || c == '_' || c == '-'
     }
 
+    if s.is_empty() {
+        return Err(ParseIdentError::Empty);
+    }
+
     let mut chars = s.chars();
     if chars
         .next()
@@ -22,20 +37,134 @@ fn is_valid_ident(s: &str) -> Result<(), ParseIdentError> {
     }
 }
 
+/// A valid typst identifier, such as a package name.
+///
+/// Hashing an identifier is equivalent to hashing its string, equal
+/// identifiers therefore always have equal hashes within a process. Like those
+/// of [`String`], hash values are not stable across process runs when used with
+/// a randomized hasher, such as the default hasher of
+/// [`HashMap`][std::collections::HashMap].
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Ident(String);
 
-#[derive(Debug, thiserror::Error)]
+impl Ident {
+    /// Returns `true` if both identifiers are equal ignoring ASCII case. Package
+    /// names are case-insensitive in practice, their canonical form is
+    /// lowercase, see [`Ident::to_lowercase`].
+    pub fn eq_ignore_case(&self, other: &Ident) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+
+    /// Returns the canonical lowercase form of this identifier, only ASCII
+    /// letters are lowercased.
+    ///
+    /// # Panics
+    /// Panics if the lowercased identifier is invalid, this cannot happen as
+    /// lowercasing ASCII letters keeps them valid identifier characters.
+    pub fn to_lowercase(&self) -> Ident {
+        let lower = self.0.to_ascii_lowercase();
+        is_valid_ident(&lower).expect("lowercased identifier must be valid");
+        Ident(lower)
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum ParseIdentError {
-    #[error("url contained invalid byte")]
+    #[error("identifier must not be empty")]
+    Empty,
+
+    #[error("identifier contained invalid character")]
     ContainsInvalidChar,
 }
 
-define_formatting!(Ident);
-define_conversions!(Ident, ParseIdentError, is_valid_ident);
-define_serde!(Ident, ParseIdentError, is_valid_ident, "an identifier");
+define_newtype!(Ident, ParseIdentError, is_valid_ident, "an identifier");
+define_str_eq!(Ident);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Ident {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utils::{arbitrary_str, LOWER_ALNUM};
+
+        let start = b"_abcdefghijklmnopqrstuvwxyz";
+        let rest = [LOWER_ALNUM, b"_-"].concat();
+        let ident = arbitrary_str(u, start, &rest, 32)?;
+        ident.parse().map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
 
 #[cfg(test)]
-mod test {
-    // TODO
+mod tests {
+    use std::str::FromStr;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::assert_ok;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(is_valid_ident(""), Err(ParseIdentError::Empty));
+        assert_eq!(
+            is_valid_ident("-foo"),
+            Err(ParseIdentError::ContainsInvalidChar)
+        );
+        assert_eq!(
+            is_valid_ident("foo bar"),
+            Err(ParseIdentError::ContainsInvalidChar)
+        );
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::hash_map::RandomState;
+        use std::hash::BuildHasher;
+
+        let state = RandomState::new();
+        let a = Ident::from_str("foo").unwrap();
+        let b = Ident::from_str("foo").unwrap();
+        assert_eq!(state.hash_one(&a), state.hash_one(&b));
+        assert_eq!(state.hash_one(&a), state.hash_one("foo"));
+    }
+
+    #[test]
+    fn ignore_case() {
+        let ident = Ident::from_str("Foo-Bar").unwrap();
+        assert!(ident.eq_ignore_case(&Ident::from_str("foo-BAR").unwrap()));
+        assert!(!ident.eq_ignore_case(&Ident::from_str("foo-baz").unwrap()));
+        assert_eq!(ident.to_lowercase(), "foo-bar");
+    }
+
+    #[test]
+    fn str_eq() {
+        let ident = Ident::from_str("foo").unwrap();
+        assert_eq!(ident, "foo");
+        assert_eq!(*"foo", ident);
+        assert_eq!(ident, String::from("foo"));
+        assert_eq!(String::from("foo"), ident);
+        assert_ne!(ident, "bar");
+    }
+
+    #[test]
+    fn valid() {
+        assert_ok!(is_valid_ident("foo"));
+        assert_ok!(is_valid_ident("_foo-bar"));
+        assert_ok!(is_valid_ident("bär"));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip(s in "\\PC{0,16}") {
+            if let Ok(ident) = Ident::from_str(&s) {
+                prop_assert_eq!(Ident::from_str(&ident), Ok(ident));
+            }
+        }
+
+        #[test]
+        fn prop_invalid_start(s in "[0-9-][a-z0-9_-]{0,16}") {
+            prop_assert_eq!(
+                Ident::from_str(&s),
+                Err(ParseIdentError::ContainsInvalidChar)
+            );
+        }
+    }
 }
@@ -8,6 +8,14 @@ use thiserror::Error;
 
 use crate::define_formatting;
 
+/// Checks whether `s` is a valid license expression without constructing a
+/// [`License`], valid expressions only contain OSI-approved SPDX licenses.
+///
+/// Returns an error if `s` is not a valid license expression.
+pub fn validate_license(s: &str) -> Result<(), ParseLicenseError> {
+    is_valid_license(s).map(|_| ())
+}
+
 fn is_valid_license(s: &str) -> Result<Expression, ParseLicenseError> {
     let expr = Expression::parse(s)?;
 
@@ -24,9 +32,138 @@ fn is_valid_license(s: &str) -> Result<Expression, ParseLicenseError> {
     Ok(expr)
 }
 
+/// SPDX identifiers of common licenses which require the license text or a
+/// copyright notice to be included when redistributing the licensed work.
+const ATTRIBUTION_LICENSES: &[&str] = &[
+    "AFL-3.0",
+    "AGPL-3.0",
+    "AGPL-3.0-only",
+    "AGPL-3.0-or-later",
+    "Apache-2.0",
+    "Artistic-2.0",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "BSL-1.0",
+    "EPL-2.0",
+    "EUPL-1.2",
+    "GPL-2.0",
+    "GPL-2.0-only",
+    "GPL-2.0-or-later",
+    "GPL-3.0",
+    "GPL-3.0-only",
+    "GPL-3.0-or-later",
+    "ISC",
+    "LGPL-2.1",
+    "LGPL-2.1-only",
+    "LGPL-2.1-or-later",
+    "LGPL-3.0",
+    "LGPL-3.0-only",
+    "LGPL-3.0-or-later",
+    "MIT",
+    "MPL-2.0",
+    "NCSA",
+    "OFL-1.1",
+    "UPL-1.0",
+    "Zlib",
+];
+
 #[derive(Clone)]
 pub struct License(Expression);
 
+impl License {
+    /// The url of the SPDX license expression specification, this describes the
+    /// format accepted by [`License::parse_spdx_expression`].
+    pub const SPDX_FORMAT_URL: &'static str =
+        "https://spdx.github.io/spdx-spec/v2.3/SPDX-license-expressions/";
+
+    /// Parses a license from an SPDX license expression, this is an alias for
+    /// [`License::from_str`][std::str::FromStr::from_str]. See
+    /// [`License::SPDX_FORMAT_URL`] for the expression format.
+    ///
+    /// Returns an error if `s` is not a valid expression or contains licenses
+    /// which are not OSI-approved.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::license::License;
+    ///
+    /// let license = License::parse_spdx_expression("MIT OR Apache-2.0")?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_spdx_expression(s: &str) -> Result<Self, ParseLicenseError> {
+        s.parse()
+    }
+
+    /// The SPDX license expression of this license.
+    pub fn expression(&self) -> &Expression {
+        &self.0
+    }
+
+    /// Returns `true` if redistributing a work under this license likely
+    /// requires including the license text or a copyright notice. If the
+    /// expression offers a choice, such as `MIT OR Unlicense`, then this only
+    /// returns `true` if every choice requires attribution.
+    ///
+    /// This is an approximation based on a hardcoded list of common licenses,
+    /// always verify the actual terms of a license.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::license::License;
+    ///
+    /// assert!("MIT".parse::<License>()?.requires_attribution());
+    /// assert!(!"Unlicense".parse::<License>()?.requires_attribution());
+    /// assert!(!"MIT OR Unlicense".parse::<License>()?.requires_attribution());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn requires_attribution(&self) -> bool {
+        !self.0.evaluate(|req| {
+            req.license
+                .id()
+                .is_some_and(|id| !ATTRIBUTION_LICENSES.contains(&id.name))
+        })
+    }
+
+    /// The number of individual license requirements in this license's
+    /// expression, regardless of how they are combined.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::license::License;
+    ///
+    /// assert_eq!("MIT".parse::<License>()?.count_requirements(), 1);
+    /// assert_eq!(
+    ///     "MIT OR Apache-2.0 OR GPL-3.0-only".parse::<License>()?.count_requirements(),
+    ///     3,
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn count_requirements(&self) -> usize {
+        self.0.requirements().count()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for License {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let license = u.choose(&[
+            "Apache-2.0",
+            "BSD-2-Clause",
+            "BSD-3-Clause",
+            "GPL-3.0-only",
+            "ISC",
+            "LGPL-3.0-or-later",
+            "MIT",
+            "MIT OR Apache-2.0",
+            "MPL-2.0",
+        ])?;
+
+        license
+            .parse()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl PartialEq for License {
     fn eq(&self, other: &Self) -> bool {
         let (this, other): (&str, &str) = (self.0.as_ref(), other.0.as_ref());
@@ -38,8 +175,7 @@ impl Eq for License {}
 
 impl PartialOrd for License {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let (this, other): (&str, &str) = (self.0.as_ref(), other.0.as_ref());
-        this.partial_cmp(other)
+        Some(self.cmp(other))
     }
 }
 
@@ -136,5 +272,45 @@ impl<'de> Deserialize<'de> for License {
 
 #[cfg(test)]
 mod tests {
-    // TODO
+    use super::*;
+
+    #[test]
+    fn validate() {
+        assert!(validate_license("MIT OR Apache-2.0").is_ok());
+        assert!(matches!(
+            validate_license("LicenseRef-Foo"),
+            Err(ParseLicenseError::ContainsReferencer)
+        ));
+        assert!(matches!(
+            validate_license("CC-BY-4.0"),
+            Err(ParseLicenseError::NotOSIApproved)
+        ));
+    }
+
+    #[test]
+    fn requires_attribution() {
+        fn requires(s: &str) -> bool {
+            License::from_str(s).unwrap().requires_attribution()
+        }
+
+        assert!(requires("MIT"));
+        assert!(requires("GPL-3.0-or-later"));
+        assert!(requires("MIT OR Apache-2.0"));
+        assert!(requires("MIT AND Unlicense"));
+
+        assert!(!requires("Unlicense"));
+        assert!(!requires("0BSD"));
+        assert!(!requires("MIT-0 OR Apache-2.0"));
+    }
+
+    #[test]
+    fn count_requirements() {
+        fn count(s: &str) -> usize {
+            License::from_str(s).unwrap().count_requirements()
+        }
+
+        assert_eq!(count("MIT"), 1);
+        assert_eq!(count("MIT OR Apache-2.0"), 2);
+        assert_eq!(count("(MIT OR Apache-2.0) AND Unlicense"), 3);
+    }
 }
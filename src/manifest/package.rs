@@ -1,32 +1,45 @@
 //! Typst package metadata.
 
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
 
 use semver::Version;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use url::Url;
 
-use super::author::Author;
+use super::author::{Author, Contact};
 use super::categories::Category;
 use super::disciplines::Discipline;
 use super::ident::Ident;
 use super::license::License;
+use super::package_id::PackageId;
+use super::package_ref::PackageRef;
+use super::validation::{has_extension, ValidationError};
 use super::website::Website;
+use crate::heuristics::{LIB_FILE, MAIN_FILE};
+use crate::utils::serialize_sorted;
 
 /// The `package` key in the manifest, storing a package's metadata.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Two packages are equal if all of their fields are equal, sets such as the
+/// authors or keywords are compared regardless of their order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(deny_unknown_fields)]
 pub struct Package {
     /// The name of the package.
     pub name: Ident,
 
     /// The current verison of the packge.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_version))]
     pub version: Version,
 
     /// The primary module of the package.
     pub entrypoint: PathBuf,
 
     /// The authors of the package.
+    #[serde(serialize_with = "serialize_authors")]
     pub authors: HashSet<Author>,
 
     /// The license expression for the package.
@@ -45,26 +58,905 @@ pub struct Package {
 
     /// The keywords for the package.
     #[serde(default)]
+    #[serde(serialize_with = "serialize_sorted")]
     #[serde(skip_serializing_if = "HashSet::is_empty")]
     pub keywords: HashSet<String>,
 
     /// The categories for the package.
     #[serde(default)]
+    #[serde(serialize_with = "serialize_sorted")]
     #[serde(skip_serializing_if = "HashSet::is_empty")]
     pub categories: HashSet<Category>,
 
     /// The disciplines for the package.
     #[serde(default)]
+    #[serde(serialize_with = "serialize_sorted")]
     #[serde(skip_serializing_if = "HashSet::is_empty")]
     pub disciplines: HashSet<Discipline>,
 
     /// The minimum compiler version for the package.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_compiler))]
     pub compiler: Option<Version>,
 
     /// The excluded paths of this package. These paths are ignored by the
     /// package manager's bundler.
     #[serde(default)]
+    #[serde(serialize_with = "serialize_sorted")]
     #[serde(skip_serializing_if = "HashSet::is_empty")]
     pub exclude: HashSet<PathBuf>,
 }
+
+/// Sorts the authors by their name and then by their contact, this is shared
+/// by [`Package::authors_sorted`] and the serialization of the authors.
+fn sort_authors(authors: &HashSet<Author>) -> Vec<&Author> {
+    let mut authors: Vec<_> = authors.iter().collect();
+    authors.sort_by_cached_key(|a| (&a.name, a.to_string()));
+    authors
+}
+
+/// Serializes the authors in the order of [`Package::authors_sorted`].
+fn serialize_authors<S>(authors: &HashSet<Author>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(sort_authors(authors))
+}
+
+#[cfg(feature = "arbitrary")]
+fn arbitrary_version(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Version> {
+    Ok(Version::new(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?))
+}
+
+#[cfg(feature = "arbitrary")]
+fn arbitrary_compiler(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Option<Version>> {
+    u.arbitrary::<bool>()?
+        .then(|| arbitrary_version(u))
+        .transpose()
+}
+
+impl Package {
+    /// The TOML keys of all fields which are required in the `package` key.
+    pub const REQUIRED_FIELDS: &'static [&'static str] = &[
+        "name",
+        "version",
+        "entrypoint",
+        "authors",
+        "license",
+        "description",
+    ];
+
+    /// The TOML keys of all fields which are optional in the `package` key.
+    pub const OPTIONAL_FIELDS: &'static [&'static str] = &[
+        "homepage",
+        "repository",
+        "keywords",
+        "categories",
+        "disciplines",
+        "compiler",
+        "exclude",
+    ];
+
+    /// Sets the name of this package.
+    pub fn with_name(mut self, name: Ident) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Sets the version of this package.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the entrypoint of this package, see [`Package::set_entrypoint`]
+    /// for a validating alternative.
+    pub fn with_entrypoint(mut self, entrypoint: impl Into<PathBuf>) -> Self {
+        self.entrypoint = entrypoint.into();
+        self
+    }
+
+    /// Sets the license of this package.
+    pub fn with_license(mut self, license: License) -> Self {
+        self.license = license;
+        self
+    }
+
+    /// Sets the description of this package.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds an author to this package.
+    pub fn with_author(mut self, author: Author) -> Self {
+        self.authors.insert(author);
+        self
+    }
+
+    /// Adds a category to this package.
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.categories.insert(category);
+        self
+    }
+
+    /// Adds a discipline to this package.
+    pub fn with_discipline(mut self, discipline: Discipline) -> Self {
+        self.disciplines.insert(discipline);
+        self
+    }
+
+    /// Adds a keyword to this package.
+    pub fn with_keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.keywords.insert(keyword.into());
+        self
+    }
+
+    /// Adds an excluded path to this package.
+    pub fn with_exclude(mut self, exclude: impl Into<PathBuf>) -> Self {
+        self.exclude.insert(exclude.into());
+        self
+    }
+
+    /// Sets the homepage of this package.
+    pub fn with_homepage(mut self, homepage: Website) -> Self {
+        self.homepage = Some(homepage);
+        self
+    }
+
+    /// Sets the repository of this package.
+    pub fn with_repository(mut self, repository: Website) -> Self {
+        self.repository = Some(repository);
+        self
+    }
+
+    /// Sets the minimum compiler version of this package.
+    pub fn with_compiler(mut self, compiler: Version) -> Self {
+        self.compiler = Some(compiler);
+        self
+    }
+
+    /// Sets the entrypoint of this package.
+    ///
+    /// Returns an error without changing the entrypoint if it is not a
+    /// relative path with a `.typ` extension.
+    pub fn set_entrypoint(&mut self, entrypoint: PathBuf) -> Result<(), ValidationError> {
+        if entrypoint.is_absolute() {
+            return Err(ValidationError::AbsoluteEntrypoint);
+        }
+
+        if !has_extension(&entrypoint, &["typ"]) {
+            return Err(ValidationError::BadEntrypointExtension);
+        }
+
+        self.entrypoint = entrypoint;
+        Ok(())
+    }
+
+    /// Trims leading and trailing whitespace from the description.
+    pub fn normalize_description(&mut self) {
+        self.description = self.description.trim().to_owned();
+    }
+
+    /// Normalizes the names of all authors, see [`Author::normalize`], and
+    /// normalizes their website contacts, see [`Website::normalize`]. Authors
+    /// which become equal are merged.
+    pub fn normalize_authors(&mut self) {
+        self.authors = self
+            .authors
+            .drain()
+            .map(|author| {
+                let mut author = author.normalize();
+                if let Some(Contact::Website(website)) = author.contact {
+                    author.contact = Some(Contact::Website(website.normalize()));
+                }
+                author
+            })
+            .collect();
+    }
+
+    /// Trims leading and trailing whitespace from all keywords, keywords which
+    /// become equal are merged.
+    pub fn normalize_keywords(&mut self) {
+        self.keywords = self
+            .keywords
+            .drain()
+            .map(|keyword| keyword.trim().to_owned())
+            .collect();
+    }
+
+    /// Normalizes the homepage and repository, see [`Website::normalize`].
+    pub fn normalize_websites(&mut self) {
+        self.homepage = self.homepage.take().map(Website::normalize);
+        self.repository = self.repository.take().map(Website::normalize);
+    }
+
+    /// Normalizes the fields of this package in place, such that two logically
+    /// identical packages compare equal and serialize identically.
+    ///
+    /// This applies all normalization steps in the following order:
+    /// 1. [`Package::normalize_description`],
+    /// 2. [`Package::normalize_authors`],
+    /// 3. [`Package::normalize_keywords`],
+    /// 4. [`Package::normalize_websites`].
+    ///
+    /// The steps touch disjoint fields, so the order does not affect the
+    /// result, but new steps will be appended to this list.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let mut manifest = Manifest::from_str(r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = [" John  Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "  Bar "
+    ///     keywords = ["a ", "a"]
+    /// "#)?;
+    ///
+    /// manifest.package.normalize_fields();
+    /// assert_eq!(manifest.package.description, "Bar");
+    /// assert_eq!(manifest.package.authors_sorted()[0].name, "John Doe");
+    /// assert_eq!(manifest.package.keywords_sorted(), ["a"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn normalize_fields(&mut self) {
+        self.normalize_description();
+        self.normalize_authors();
+        self.normalize_keywords();
+        self.normalize_websites();
+    }
+
+    /// The authors of this package sorted by their name and then by their
+    /// contact, this is also the order in which they are serialized.
+    pub fn authors_sorted(&self) -> Vec<&Author> {
+        sort_authors(&self.authors)
+    }
+
+    /// The keywords of this package in lexicographic order, this is also the
+    /// order in which they are serialized.
+    pub fn keywords_sorted(&self) -> Vec<&str> {
+        let mut keywords: Vec<_> = self.keywords.iter().map(String::as_str).collect();
+        keywords.sort_unstable();
+        keywords
+    }
+
+    /// An iterator over the keywords of this package, see
+    /// [`Package::keywords_sorted`] for the order.
+    pub fn keywords_iter(&self) -> impl Iterator<Item = &str> {
+        self.keywords_sorted().into_iter()
+    }
+
+    /// Returns `true` if this package has the given keyword, keywords are
+    /// compared ASCII case-insensitively.
+    pub fn keywords_contains(&self, keyword: &str) -> bool {
+        self.keywords
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(keyword))
+    }
+
+    /// The number of authors of this package.
+    pub fn authors_count(&self) -> usize {
+        self.authors.len()
+    }
+
+    /// The number of keywords of this package.
+    pub fn keywords_count(&self) -> usize {
+        self.keywords.len()
+    }
+
+    /// The number of categories of this package.
+    pub fn categories_count(&self) -> usize {
+        self.categories.len()
+    }
+
+    /// The number of disciplines of this package.
+    pub fn disciplines_count(&self) -> usize {
+        self.disciplines.len()
+    }
+
+    /// The categories of this package in the order of [`Category::ALL`].
+    pub fn categories_sorted(&self) -> Vec<Category> {
+        let mut categories: Vec<_> = self.categories.iter().copied().collect();
+        categories.sort();
+        categories
+    }
+
+    /// The disciplines of this package in the order of [`Discipline::ALL`].
+    pub fn disciplines_sorted(&self) -> Vec<Discipline> {
+        let mut disciplines: Vec<_> = self.disciplines.iter().copied().collect();
+        disciplines.sort();
+        disciplines
+    }
+
+    /// The kebab-case names of this package's categories, see
+    /// [`Package::categories_sorted`] for the order.
+    pub fn categories_as_str_slice(&self) -> Vec<&'static str> {
+        self.categories_sorted()
+            .into_iter()
+            .map(Category::to_str)
+            .collect()
+    }
+
+    /// The kebab-case names of this package's disciplines, see
+    /// [`Package::disciplines_sorted`] for the order.
+    pub fn disciplines_as_str_slice(&self) -> Vec<&'static str> {
+        self.disciplines_sorted()
+            .into_iter()
+            .map(Discipline::to_str)
+            .collect()
+    }
+
+    /// Exports the metadata of this package as flat key-value pairs, such as
+    /// `TYPST_PACKAGE_NAME` or `TYPST_PACKAGE_VERSION`, which are suitable for
+    /// environment variables. Sets are joined with commas in sorted order,
+    /// unset optional fields and empty sets are omitted. The excluded paths
+    /// are not exported.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let manifest = Manifest::from_str(r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    ///     categories = ["layout", "components"]
+    /// "#)?;
+    ///
+    /// let metadata = manifest.package.export_metadata();
+    /// assert_eq!(metadata["TYPST_PACKAGE_VERSION"], "0.1.0");
+    /// assert_eq!(metadata["TYPST_PACKAGE_CATEGORIES"], "components,layout");
+    /// assert!(!metadata.contains_key("TYPST_PACKAGE_HOMEPAGE"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn export_metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        let mut insert = |key: &str, value: String| {
+            metadata.insert(format!("TYPST_PACKAGE_{key}"), value);
+        };
+
+        insert("NAME", self.name.to_string());
+        insert("VERSION", self.version.to_string());
+        insert("ENTRYPOINT", self.entrypoint.display().to_string());
+        insert("LICENSE", self.license.to_string());
+        insert("DESCRIPTION", self.description.clone());
+
+        let authors: Vec<_> = self
+            .authors_sorted()
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        let keywords: Vec<_> = self.keywords_iter().collect();
+        let sets = [
+            ("AUTHORS", authors.join(",")),
+            ("KEYWORDS", keywords.join(",")),
+            ("CATEGORIES", self.categories_as_str_slice().join(",")),
+            ("DISCIPLINES", self.disciplines_as_str_slice().join(",")),
+        ];
+        for (key, value) in sets {
+            if !value.is_empty() {
+                insert(key, value);
+            }
+        }
+
+        if let Some(homepage) = &self.homepage {
+            insert("HOMEPAGE", homepage.to_string());
+        }
+
+        if let Some(repository) = &self.repository {
+            insert("REPOSITORY", repository.to_string());
+        }
+
+        if let Some(compiler) = &self.compiler {
+            insert("COMPILER", compiler.to_string());
+        }
+
+        metadata
+    }
+
+    /// The id of this package, consisting of its name and version.
+    pub fn id(&self) -> PackageId {
+        PackageId {
+            name: self.name.clone(),
+            version: self.version.clone(),
+        }
+    }
+
+    /// Returns `true` if this package is the one referenced by `package_ref`,
+    /// i.e. if their names and versions are equal. The namespace of the
+    /// reference is not checked.
+    pub fn satisfies_ref(&self, package_ref: &PackageRef) -> bool {
+        self == package_ref
+    }
+
+    /// The minimum typst version required by this package, this is the
+    /// `compiler` key of the manifest.
+    pub fn minimum_typst_version(&self) -> Option<&Version> {
+        self.compiler.as_ref()
+    }
+
+    /// Returns `true` if this package's version has no pre-release component.
+    pub fn is_stable(&self) -> bool {
+        self.version.pre.is_empty()
+    }
+
+    /// Returns `true` if this package's version has a pre-release component,
+    /// this is the complement of [`Package::is_stable`].
+    pub fn is_prerelease(&self) -> bool {
+        !self.is_stable()
+    }
+
+    /// Returns `true` if the entrypoint of this package is named `lib.typ`.
+    /// By convention such packages are libraries, this is a heuristic and not
+    /// enforced by typst.
+    pub fn is_library(&self) -> bool {
+        self.entrypoint.file_name() == Some(LIB_FILE.as_ref())
+    }
+
+    /// Returns `true` if the entrypoint of this package is named `main.typ`.
+    /// By convention such packages are standalone documents, this is a
+    /// heuristic and not enforced by typst.
+    pub fn is_document(&self) -> bool {
+        self.entrypoint.file_name() == Some(MAIN_FILE.as_ref())
+    }
+
+    /// The version of this package as a string, e.g. `0.1.0`.
+    pub fn version_string(&self) -> String {
+        self.version.to_string()
+    }
+
+    /// The name and version of this package as a string, e.g. `foo v0.1.0`.
+    /// See [`PackageId`] for the `foo:0.1.0` form.
+    pub fn name_version_string(&self) -> String {
+        format!("{} v{}", self.name, self.version)
+    }
+
+    /// Returns `true` if the description is empty or only contains whitespace.
+    pub fn description_is_empty(&self) -> bool {
+        self.description.trim().is_empty()
+    }
+
+    /// Returns `true` if the description is a known placeholder, such as
+    /// `A Typst package.`, which was likely generated by a scaffolding tool.
+    /// Descriptions are compared case-insensitively and ignoring surrounding
+    /// whitespace and a trailing period.
+    pub fn description_is_placeholder(&self) -> bool {
+        const PLACEHOLDERS: &[&str] = &[
+            "a typst package",
+            "a typst template",
+            "a short description of the package",
+            "a short description of your package",
+            "description",
+            "package description",
+            "todo",
+            "tbd",
+            "lorem ipsum",
+        ];
+
+        let description = self.description.trim();
+        let description = description.strip_suffix('.').unwrap_or(description);
+        PLACEHOLDERS
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(description.trim_end()))
+    }
+
+    /// Returns the description truncated to at most `max_chars` unicode
+    /// scalar values, followed by `…` if it was truncated.
+    pub fn description_truncated(&self, max_chars: usize) -> String {
+        match self.description.char_indices().nth(max_chars) {
+            Some((idx, _)) => format!("{}…", &self.description[..idx]),
+            None => self.description.clone(),
+        }
+    }
+
+    /// Returns `true` if `path` is one of this package's excluded paths or lies
+    /// within one of them. Paths are compared component-wise and relative to
+    /// the package root, such that `docs/a.png` is excluded by `docs`, but
+    /// `docs-old` is not.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::Manifest;
+    /// use std::path::Path;
+    ///
+    /// let manifest = Manifest::from_str(r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     entrypoint = "src/lib.typ"
+    ///     authors = ["John Doe <john@doe.com>"]
+    ///     license = "MIT"
+    ///     description = "Bar"
+    ///     exclude = ["docs"]
+    /// "#)?;
+    ///
+    /// assert!(manifest.package.exclude_contains(Path::new("docs/a.png")));
+    /// assert!(!manifest.package.exclude_contains(Path::new("src/lib.typ")));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn exclude_contains(&self, path: &Path) -> bool {
+        self.exclude.iter().any(|entry| path.starts_with(entry))
+    }
+
+    /// Returns `true` if this package excludes any paths.
+    pub fn has_exclusions(&self) -> bool {
+        !self.exclude.is_empty()
+    }
+
+    /// The number of paths excluded by this package.
+    pub fn total_excluded_count(&self) -> usize {
+        self.exclude.len()
+    }
+
+    /// Returns `true` if the entrypoint of this package exists in the package
+    /// at `package_root`.
+    ///
+    /// Returns an error if the existence of the entrypoint could not be
+    /// determined, see [`Path::try_exists`].
+    pub fn verify_entrypoint_exists(&self, package_root: &Path) -> io::Result<bool> {
+        package_root.join(&self.entrypoint).try_exists()
+    }
+
+    /// Checks whether the entrypoint and all excluded paths of this package
+    /// exist in the package at `package_root` and returns those which don't,
+    /// sorted and relative to `package_root`.
+    ///
+    /// Returns an error if the existence of any path could not be determined,
+    /// see [`Path::try_exists`].
+    pub fn verify_all_files_exist(&self, package_root: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut exclude: Vec<_> = self.exclude.iter().collect();
+        exclude.sort();
+
+        let mut missing = vec![];
+        for path in std::iter::once(&self.entrypoint).chain(exclude) {
+            if !package_root.join(path).try_exists()? {
+                missing.push(path.clone());
+            }
+        }
+
+        Ok(missing)
+    }
+
+    /// Checks whether the host of this package's repository matches the host
+    /// of the `origin` remote in the git repository at `project_root`.
+    ///
+    /// Returns `None` if the package has no repository, if `.git/config` is
+    /// absent or unreadable or if it contains no `origin` remote.
+    pub fn repository_host_matches_git_remote(&self, project_root: &Path) -> Option<bool> {
+        let repository = self.repository.as_ref()?;
+        let config = fs::read_to_string(project_root.join(".git").join("config")).ok()?;
+        let remote = git_origin_host(&config)?;
+
+        Some(
+            repository
+                .host()
+                .is_some_and(|host| host.eq_ignore_ascii_case(&remote)),
+        )
+    }
+}
+
+/// Extracts the host of the `origin` remote url from the contents of a git
+/// config file. Supports both proper urls and scp-like ssh remotes like
+/// `git@github.com:tingerrr/typst-project.git`.
+fn git_origin_host(config: &str) -> Option<String> {
+    let mut in_origin = false;
+
+    for line in config.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_origin = line == r#"[remote "origin"]"#;
+            continue;
+        }
+
+        if !in_origin {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        if key.trim() != "url" {
+            continue;
+        }
+
+        let value = value.trim();
+        return if value.contains("://") {
+            Url::parse(value).ok()?.host_str().map(ToOwned::to_owned)
+        } else {
+            let (host, _) = value.split_once(':')?;
+            Some(host.rsplit('@').next()?.to_owned())
+        };
+    }
+
+    None
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn package() -> Package {
+        toml::from_str(
+            r#"
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = ["John Doe <john@doe.com>"]
+            license = "MIT"
+            description = "Bär baz"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn normalize_fields() {
+        let mut package = package()
+            .with_description(" Bär baz\n")
+            .with_keyword("a ")
+            .with_keyword(" a")
+            .with_homepage(Website::try_from("https://typst.app/docs/".to_owned()).unwrap());
+        package.authors = HashSet::from([
+            " Jose\u{301}  Doe <https://mha.ug/blog/>".parse().unwrap(),
+            "José Doe <https://mha.ug/blog>".parse().unwrap(),
+        ]);
+
+        package.normalize_fields();
+
+        assert_eq!(package.description, "Bär baz");
+        assert_eq!(
+            package.authors,
+            HashSet::from(["José Doe <https://mha.ug/blog>".parse().unwrap()])
+        );
+        assert_eq!(package.keywords_sorted(), ["a"]);
+        assert_eq!(package.homepage.as_deref(), Some("https://typst.app/docs"));
+    }
+
+    #[test]
+    fn keywords() {
+        let package = package()
+            .with_keyword("Layout")
+            .with_keyword("figures")
+            .with_keyword("boxes");
+
+        assert_eq!(package.keywords_sorted(), ["Layout", "boxes", "figures"]);
+        assert!(package.keywords_iter().eq(package.keywords_sorted()));
+        assert!(package.keywords_contains("layout"));
+        assert!(package.keywords_contains("FIGURES"));
+        assert!(!package.keywords_contains("figure"));
+        assert_eq!(package.keywords_count(), 3);
+    }
+
+    #[test]
+    fn counts() {
+        let package = package()
+            .with_category(Category::Layout)
+            .with_category(Category::Layout)
+            .with_discipline(Discipline::Biology);
+
+        assert_eq!(package.authors_count(), package.authors.len());
+        assert_eq!(package.categories_count(), 1);
+        assert_eq!(package.disciplines_count(), 1);
+        assert_eq!(package.keywords_count(), 0);
+    }
+
+    #[test]
+    fn exclude_contains() {
+        let package = package()
+            .with_exclude("docs")
+            .with_exclude("assets/logo.svg");
+
+        assert!(package.exclude_contains(Path::new("docs")));
+        assert!(package.exclude_contains(Path::new("docs/a.png")));
+        assert!(package.exclude_contains(Path::new("docs/images/b.png")));
+        assert!(package.exclude_contains(Path::new("assets/logo.svg")));
+        assert!(!package.exclude_contains(Path::new("assets")));
+        assert!(!package.exclude_contains(Path::new("docs-old/a.png")));
+        assert!(!package.exclude_contains(Path::new("src/lib.typ")));
+    }
+
+    #[test]
+    fn exclusions() {
+        assert!(!package().has_exclusions());
+        assert_eq!(package().total_excluded_count(), 0);
+
+        let package = package().with_exclude("docs").with_exclude("tests");
+        assert!(package.has_exclusions());
+        assert_eq!(package.total_excluded_count(), 2);
+    }
+
+    #[test]
+    fn authors_serialization_order() {
+        let package = package()
+            .with_author("Martin <@reknih>".parse().unwrap())
+            .with_author("Jane Doe".parse().unwrap())
+            .with_author("John Doe <@john>".parse().unwrap());
+
+        let table = toml::Table::try_from(&package).unwrap();
+        let serialized: Vec<_> = table["authors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap().to_owned())
+            .collect();
+        let sorted: Vec<_> = package
+            .authors_sorted()
+            .into_iter()
+            .map(Author::to_string)
+            .collect();
+
+        assert_eq!(serialized, sorted);
+    }
+
+    #[test]
+    fn fields() {
+        let table = toml::Table::try_from(package()).unwrap();
+        for key in table.keys() {
+            assert!(
+                Package::REQUIRED_FIELDS.contains(&key.as_str())
+                    || Package::OPTIONAL_FIELDS.contains(&key.as_str())
+            );
+        }
+
+        for field in Package::REQUIRED_FIELDS {
+            assert!(table.contains_key(*field));
+        }
+    }
+
+    #[test]
+    fn builder() {
+        let package = package()
+            .with_name("bar".parse().unwrap())
+            .with_version(Version::new(1, 2, 3))
+            .with_entrypoint("main.typ")
+            .with_license("Apache-2.0".parse().unwrap())
+            .with_description("Baz");
+
+        assert_eq!(package.name, "bar");
+        assert_eq!(package.version, Version::new(1, 2, 3));
+        assert_eq!(package.entrypoint, Path::new("main.typ"));
+        assert_eq!(package.license.to_string(), "Apache-2.0");
+        assert_eq!(package.description, "Baz");
+    }
+
+    #[test]
+    fn set_entrypoint() {
+        let mut package = package();
+        assert_eq!(
+            package.set_entrypoint(PathBuf::from("/src/lib.typ")),
+            Err(ValidationError::AbsoluteEntrypoint)
+        );
+        assert_eq!(
+            package.set_entrypoint(PathBuf::from("src/lib.rs")),
+            Err(ValidationError::BadEntrypointExtension)
+        );
+        assert_eq!(package.entrypoint, Path::new("src/lib.typ"));
+
+        assert_eq!(package.set_entrypoint(PathBuf::from("main.typ")), Ok(()));
+        assert_eq!(package.entrypoint, Path::new("main.typ"));
+    }
+
+    #[test]
+    fn description_checks() {
+        let package = package();
+        assert!(!package.description_is_empty());
+        assert!(!package.description_is_placeholder());
+
+        let package = package.with_description(" \n");
+        assert!(package.description_is_empty());
+
+        let package = package.with_description("A Typst package.");
+        assert!(package.description_is_placeholder());
+
+        let package = package.with_description(" TODO ");
+        assert!(package.description_is_placeholder());
+    }
+
+    #[test]
+    fn library_or_document() {
+        let package = package();
+        assert!(package.is_library());
+        assert!(!package.is_document());
+
+        let package = package.with_entrypoint("main.typ");
+        assert!(!package.is_library());
+        assert!(package.is_document());
+
+        let package = package.with_entrypoint("src/thesis.typ");
+        assert!(!package.is_library());
+        assert!(!package.is_document());
+    }
+
+    #[test]
+    fn verify_files_exist() {
+        let root = tempfile::tempdir().unwrap();
+        let package = package().with_exclude("tests").with_exclude("docs");
+
+        assert!(!package.verify_entrypoint_exists(root.path()).unwrap());
+        assert_eq!(
+            package.verify_all_files_exist(root.path()).unwrap(),
+            [
+                PathBuf::from("src/lib.typ"),
+                PathBuf::from("docs"),
+                PathBuf::from("tests"),
+            ]
+        );
+
+        fs::create_dir_all(root.path().join("src")).unwrap();
+        fs::write(root.path().join("src/lib.typ"), "").unwrap();
+        fs::create_dir(root.path().join("tests")).unwrap();
+
+        assert!(package.verify_entrypoint_exists(root.path()).unwrap());
+        assert_eq!(
+            package.verify_all_files_exist(root.path()).unwrap(),
+            [PathBuf::from("docs")]
+        );
+    }
+
+    #[test]
+    fn version_strings() {
+        let package = package();
+        assert_eq!(package.version_string(), "0.1.0");
+        assert_eq!(package.name_version_string(), "foo v0.1.0");
+    }
+
+    #[test]
+    fn description_truncated() {
+        let package = package();
+        assert_eq!(package.description_truncated(0), "…");
+        assert_eq!(package.description_truncated(2), "Bä…");
+        assert_eq!(package.description_truncated(7), "Bär baz");
+        assert_eq!(package.description_truncated(10), "Bär baz");
+    }
+
+    #[test]
+    fn eq_package_id() {
+        let package = package();
+        assert!(package == package.id());
+        assert!(package.id() == package);
+        assert!(
+            package
+                != PackageId {
+                    name: "foo".parse().unwrap(),
+                    version: Version::new(0, 2, 0),
+                }
+        );
+    }
+
+    #[test]
+    fn git_origin_host() {
+        let config = r#"
+            [core]
+                bare = false
+            [remote "upstream"]
+                url = https://gitlab.com/foo/bar.git
+            [remote "origin"]
+                url = git@github.com:tingerrr/typst-project.git
+                fetch = +refs/heads/*:refs/remotes/origin/*
+        "#;
+        assert_eq!(
+            super::git_origin_host(config).as_deref(),
+            Some("github.com")
+        );
+
+        let config = r#"
+            [remote "origin"]
+                url = https://codeberg.org/foo/bar.git
+        "#;
+        assert_eq!(
+            super::git_origin_host(config).as_deref(),
+            Some("codeberg.org")
+        );
+
+        assert_eq!(super::git_origin_host("[core]\nbare = false"), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! Typst package identifiers.
+
+use std::fmt::Display;
+
+use semver::Version;
+use thiserror::Error;
+
+use super::ident::Ident;
+use super::package::Package;
+
+/// Identifies a specific version of a package by its name and version.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackageId {
+    /// The name of the package.
+    pub name: Ident,
+
+    /// The version of the package.
+    pub version: Version,
+}
+
+impl PackageId {
+    /// Parses a package id from a registry directory name of the form
+    /// `name-x.y.z`. Both names and versions may contain `-`, the split points
+    /// are tried from the right until both parts are valid.
+    ///
+    /// Returns an error if no split point yields a valid name and version.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::package_id::PackageId;
+    ///
+    /// let id = PackageId::parse_from_dir_name("my-package-0.1.0-beta")?;
+    /// assert_eq!(id.to_string(), "my-package:0.1.0-beta");
+    /// assert_eq!(id.to_dir_name(), "my-package-0.1.0-beta");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_from_dir_name(s: &str) -> Result<PackageId, ParsePackageIdError> {
+        s.match_indices('-')
+            .rev()
+            .find_map(|(idx, _)| {
+                Some(PackageId {
+                    name: s[..idx].parse().ok()?,
+                    version: s[idx + 1..].parse().ok()?,
+                })
+            })
+            .ok_or(ParsePackageIdError::MissingSeparator)
+    }
+
+    /// The registry directory name of this package id, this is the inverse of
+    /// [`PackageId::parse_from_dir_name`].
+    pub fn to_dir_name(&self) -> String {
+        format!("{}-{}", self.name, self.version)
+    }
+}
+
+/// An error returned by [`PackageId::parse_from_dir_name`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ParsePackageIdError {
+    #[error("missing separator between a valid name and version")]
+    MissingSeparator,
+}
+
+impl Display for PackageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.name, self.version)
+    }
+}
+
+impl PartialEq<PackageId> for Package {
+    fn eq(&self, other: &PackageId) -> bool {
+        self.name == other.name && self.version == other.version
+    }
+}
+
+impl PartialEq<Package> for PackageId {
+    fn eq(&self, other: &Package) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir_name() {
+        let id = PackageId::parse_from_dir_name("foo-bar-1.2.3").unwrap();
+        assert_eq!(id.name, "foo-bar");
+        assert_eq!(id.version, Version::new(1, 2, 3));
+        assert_eq!(id.to_dir_name(), "foo-bar-1.2.3");
+
+        let id = PackageId::parse_from_dir_name("foo-1.0.0-rc-1").unwrap();
+        assert_eq!(id.name, "foo");
+        assert_eq!(id.version.pre.as_str(), "rc-1");
+
+        assert_eq!(
+            PackageId::parse_from_dir_name("foo"),
+            Err(ParsePackageIdError::MissingSeparator)
+        );
+        assert_eq!(
+            PackageId::parse_from_dir_name("foo-bar"),
+            Err(ParsePackageIdError::MissingSeparator)
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! References to typst packages as they appear in imports.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use semver::Version;
+use thiserror::Error;
+
+use super::ident::{Ident, ParseIdentError};
+use super::package::Package;
+
+/// A reference to a package as used in typst imports, such as
+/// `@preview/foo:0.1.0`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackageRef {
+    /// The namespace of the package, e.g. `preview`.
+    pub namespace: Ident,
+
+    /// The name of the package.
+    pub name: Ident,
+
+    /// The version of the package.
+    pub version: Version,
+}
+
+#[derive(Debug, Error)]
+pub enum ParsePackageRefError {
+    #[error("package reference must start with '@'")]
+    MissingAt,
+
+    #[error("missing '/' between namespace and name")]
+    MissingSlash,
+
+    #[error("missing ':' between name and version")]
+    MissingColon,
+
+    #[error("invalid namespace")]
+    Namespace(#[source] ParseIdentError),
+
+    #[error("invalid name")]
+    Name(#[source] ParseIdentError),
+
+    #[error("invalid version")]
+    Version(#[source] semver::Error),
+}
+
+impl PackageRef {
+    /// Parses a package reference in the form used in typst imports,
+    /// `@namespace/name:version`.
+    ///
+    /// Returns an error if the reference is malformed.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::package_ref::PackageRef;
+    ///
+    /// let package = PackageRef::from_import_str("@preview/foo:0.1.0")?;
+    /// assert_eq!(package.to_import_str(), "@preview/foo:0.1.0");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_import_str(s: &str) -> Result<Self, ParsePackageRefError> {
+        let s = s.strip_prefix('@').ok_or(ParsePackageRefError::MissingAt)?;
+        let (namespace, rest) = s
+            .split_once('/')
+            .ok_or(ParsePackageRefError::MissingSlash)?;
+        let (name, version) = rest
+            .split_once(':')
+            .ok_or(ParsePackageRefError::MissingColon)?;
+
+        Ok(Self {
+            namespace: namespace.parse().map_err(ParsePackageRefError::Namespace)?,
+            name: name.parse().map_err(ParsePackageRefError::Name)?,
+            version: version.parse().map_err(ParsePackageRefError::Version)?,
+        })
+    }
+
+    /// Returns the canonical import string of this reference,
+    /// `@namespace/name:version`.
+    pub fn to_import_str(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for PackageRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{}/{}:{}", self.namespace, self.name, self.version)
+    }
+}
+
+impl FromStr for PackageRef {
+    type Err = ParsePackageRefError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_import_str(s)
+    }
+}
+
+/// A package equals a reference if their names and versions are equal, the
+/// namespace of the reference is ignored.
+impl PartialEq<PackageRef> for Package {
+    fn eq(&self, other: &PackageRef) -> bool {
+        self.name == other.name && self.version == other.version
+    }
+}
+
+impl PartialEq<Package> for PackageRef {
+    fn eq(&self, other: &Package) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            PackageRef::from_import_str("preview/foo:0.1.0"),
+            Err(ParsePackageRefError::MissingAt)
+        ));
+        assert!(matches!(
+            PackageRef::from_import_str("@foo:0.1.0"),
+            Err(ParsePackageRefError::MissingSlash)
+        ));
+        assert!(matches!(
+            PackageRef::from_import_str("@preview/foo"),
+            Err(ParsePackageRefError::MissingColon)
+        ));
+        assert!(matches!(
+            PackageRef::from_import_str("@preview/1foo:0.1.0"),
+            Err(ParsePackageRefError::Name(_))
+        ));
+        assert!(matches!(
+            PackageRef::from_import_str("@preview/foo:0.1"),
+            Err(ParsePackageRefError::Version(_))
+        ));
+    }
+
+    #[test]
+    fn valid() {
+        let package = PackageRef::from_import_str("@preview/foo-bar:0.1.0").unwrap();
+        assert_eq!(package.namespace.as_ref(), "preview");
+        assert_eq!(package.name.as_ref(), "foo-bar");
+        assert_eq!(package.version, Version::new(0, 1, 0));
+        assert_eq!(package.to_import_str(), "@preview/foo-bar:0.1.0");
+    }
+
+    #[test]
+    fn eq_package() {
+        let package = crate::manifest::package::tests::package();
+        let package_ref = PackageRef::from_import_str("@preview/foo:0.1.0").unwrap();
+        assert_eq!(package, package_ref);
+        assert_eq!(package_ref, package);
+        assert!(package.satisfies_ref(&package_ref));
+
+        let package_ref = PackageRef::from_import_str("@preview/foo:0.2.0").unwrap();
+        assert!(!package.satisfies_ref(&package_ref));
+    }
+}
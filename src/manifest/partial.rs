@@ -0,0 +1,279 @@
+//! Partial typst manifests.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::author::Author;
+use super::categories::Category;
+use super::disciplines::Discipline;
+use super::ident::Ident;
+use super::license::License;
+use super::package::Package;
+use super::template::Template;
+use super::tool::Tool;
+use super::website::Website;
+use super::{DeserializeError, Manifest};
+
+/// A typst.toml manifest in which all fields are optional, this is useful for
+/// reading incomplete manifests, such as those of newly initialized projects.
+/// Unknown fields are ignored. See [`Manifest`] for the complete manifest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ManifestPartial {
+    /// The `package` key, storing a package's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<PackagePartial>,
+
+    /// The `template` key, storing a packages's template metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<TemplatePartial>,
+
+    /// The `tool` key, storing 3rd-party configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<Tool>,
+}
+
+/// The `package` key in a partial manifest, see [`Package`] for the meaning of
+/// the individual fields.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackagePartial {
+    /// The name of the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Ident>,
+
+    /// The current version of the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<Version>,
+
+    /// The primary module of the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<PathBuf>,
+
+    /// The authors of the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authors: Option<HashSet<Author>>,
+
+    /// The license expression for the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+
+    /// The description of the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The homepage URL of the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<Website>,
+
+    /// The repository URL of the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository: Option<Website>,
+
+    /// The keywords for the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<HashSet<String>>,
+
+    /// The categories for the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<HashSet<Category>>,
+
+    /// The disciplines for the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disciplines: Option<HashSet<Discipline>>,
+
+    /// The minimum compiler version for the package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compiler: Option<Version>,
+
+    /// The excluded paths of this package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<HashSet<PathBuf>>,
+}
+
+/// The `template` key in a partial manifest, see [`Template`] for the meaning
+/// of the individual fields.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TemplatePartial {
+    /// The path of the template directory, relative to the package's root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+
+    /// The path of the template's compilation target, relative to the template's path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entrypoint: Option<PathBuf>,
+
+    /// The path of the template's thumbnail, relative to the package's root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PathBuf>,
+}
+
+/// An error returned by [`ManifestPartial::complete`] if a required field is
+/// missing.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("missing required field `{field}`")]
+pub struct MissingFieldError {
+    /// The dotted key of the missing field, e.g. `package.name`.
+    pub field: &'static str,
+}
+
+impl ManifestPartial {
+    /// Deserializes a partial manifest from the contents of a manifest file.
+    ///
+    /// Returns a error if deserialization fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::partial::ManifestPartial;
+    ///
+    /// let toml = r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    /// "#;
+    ///
+    /// let manifest = ManifestPartial::from_str(toml)?;
+    /// assert!(manifest.complete().is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(toml: &str) -> Result<Self, DeserializeError> {
+        toml::from_str(toml)
+    }
+
+    /// Converts this partial manifest into a complete [`Manifest`].
+    ///
+    /// Returns an error for the first required field which is missing.
+    pub fn complete(self) -> Result<Manifest, MissingFieldError> {
+        let Some(package) = self.package else {
+            return Err(MissingFieldError { field: "package" });
+        };
+
+        Ok(Manifest {
+            package: package.complete()?,
+            template: self.template.map(TemplatePartial::complete).transpose()?,
+            tool: self.tool,
+        })
+    }
+}
+
+fn required<T>(field: Option<T>, name: &'static str) -> Result<T, MissingFieldError> {
+    field.ok_or(MissingFieldError { field: name })
+}
+
+impl PackagePartial {
+    /// Converts this partial package into a complete [`Package`].
+    ///
+    /// Returns an error for the first required field which is missing.
+    pub fn complete(self) -> Result<Package, MissingFieldError> {
+        Ok(Package {
+            name: required(self.name, "package.name")?,
+            version: required(self.version, "package.version")?,
+            entrypoint: required(self.entrypoint, "package.entrypoint")?,
+            authors: required(self.authors, "package.authors")?,
+            license: required(self.license, "package.license")?,
+            description: required(self.description, "package.description")?,
+            homepage: self.homepage,
+            repository: self.repository,
+            keywords: self.keywords.unwrap_or_default(),
+            categories: self.categories.unwrap_or_default(),
+            disciplines: self.disciplines.unwrap_or_default(),
+            compiler: self.compiler,
+            exclude: self.exclude.unwrap_or_default(),
+        })
+    }
+}
+
+impl TemplatePartial {
+    /// Converts this partial template into a complete [`Template`].
+    ///
+    /// Returns an error for the first required field which is missing.
+    pub fn complete(self) -> Result<Template, MissingFieldError> {
+        Ok(Template {
+            path: required(self.path, "template.path")?,
+            entrypoint: required(self.entrypoint, "template.entrypoint")?,
+            thumbnail: required(self.thumbnail, "template.thumbnail")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete() {
+        let partial = ManifestPartial::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = []
+            license = "MIT"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            partial.clone().complete().unwrap_err(),
+            MissingFieldError {
+                field: "package.description"
+            }
+        );
+
+        let mut partial = partial;
+        partial.package.as_mut().unwrap().description = Some("Bar".into());
+        assert!(partial.complete().is_ok());
+
+        assert_eq!(
+            ManifestPartial::default().complete().unwrap_err(),
+            MissingFieldError { field: "package" }
+        );
+    }
+
+    #[test]
+    fn incomplete_template() {
+        let partial = ManifestPartial::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = []
+            license = "MIT"
+            description = "Bar"
+
+            [template]
+            path = "template"
+            "#,
+        )
+        .unwrap();
+
+        let template = partial.template.as_ref().unwrap();
+        assert_eq!(template.path, Some("template".into()));
+        assert_eq!(template.entrypoint, None);
+
+        assert_eq!(
+            partial.clone().complete().unwrap_err(),
+            MissingFieldError {
+                field: "template.entrypoint"
+            }
+        );
+
+        let mut partial = partial;
+        let template = partial.template.as_mut().unwrap();
+        template.entrypoint = Some("main.typ".into());
+        template.thumbnail = Some("thumbnail.png".into());
+        assert_eq!(
+            partial.complete().unwrap().template,
+            Some(Template {
+                path: "template".into(),
+                entrypoint: "main.typ".into(),
+                thumbnail: "thumbnail.png".into(),
+            })
+        );
+    }
+}
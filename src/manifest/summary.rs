@@ -0,0 +1,135 @@
+//! Lightweight typst package summaries.
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use super::categories::Category;
+use super::ident::Ident;
+use super::{DeserializeError, Manifest};
+
+/// A summary of a package's metadata, this contains only the fields needed for
+/// displaying a package in a listing and can be deserialized from a manifest
+/// without parsing and validating all of its fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageSummary {
+    /// The name of the package.
+    pub name: Ident,
+
+    /// The current verison of the packge.
+    pub version: Version,
+
+    /// The description of the package.
+    pub description: String,
+
+    /// The categories for the package.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<Category>,
+}
+
+/// A manifest containing only the package summary, all other keys are ignored.
+#[derive(Deserialize)]
+struct SummaryManifest {
+    package: PackageSummary,
+}
+
+impl PackageSummary {
+    /// Creates a summary from a fully parsed manifest, the categories are
+    /// sorted alphabetically.
+    pub fn from_manifest(manifest: &Manifest) -> Self {
+        let package = &manifest.package;
+
+        Self {
+            name: package.name.clone(),
+            version: package.version.clone(),
+            description: package.description.clone(),
+            categories: package.categories_sorted(),
+        }
+    }
+
+    /// Deserializes a package summary from the contents of a manifest file,
+    /// only the `name`, `version`, `description` and `categories` keys of the
+    /// `package` key are read.
+    ///
+    /// Returns a error if deserialization fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::summary::PackageSummary;
+    ///
+    /// let toml = r#"
+    ///     [package]
+    ///     name = "foo"
+    ///     version = "0.1.0"
+    ///     description = "Bar"
+    ///     categories = ["layout"]
+    /// "#;
+    ///
+    /// let summary = PackageSummary::from_str(toml)?;
+    /// assert_eq!(summary.name, "foo");
+    /// assert_eq!(summary.description, "Bar");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(toml: &str) -> Result<Self, DeserializeError> {
+        toml::from_str::<SummaryManifest>(toml).map(|m| m.package)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use super::*;
+    use crate::manifest::package;
+
+    #[test]
+    fn from_str() {
+        let summary = PackageSummary::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            description = "Bar"
+            categories = ["layout", "components"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(summary.name, "foo");
+        assert_eq!(summary.version, Version::new(0, 1, 0));
+        assert_eq!(summary.description, "Bar");
+        assert_eq!(summary.categories, [Category::Layout, Category::Components]);
+    }
+
+    #[test]
+    fn from_str_missing_field() {
+        assert!(PackageSummary::from_str(
+            r#"
+            [package]
+            name = "foo"
+            description = "Bar"
+            categories = ["layout"]
+            "#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn from_manifest() {
+        let manifest = Manifest::package(
+            package::tests::package()
+                .with_category(Category::Visualization)
+                .with_category(Category::Layout),
+        );
+
+        let summary = PackageSummary::from_manifest(&manifest);
+        assert_eq!(summary.name, manifest.package.name);
+        assert_eq!(summary.version, manifest.package.version);
+        assert_eq!(summary.description, manifest.package.description);
+        assert_eq!(
+            summary.categories,
+            [Category::Layout, Category::Visualization]
+        );
+    }
+}
@@ -1,8 +1,10 @@
 //! Typst template metadata.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// The `template` key in the manifest, storing a template's metadata. Given the
 /// following folder structure of a template package:
@@ -28,7 +30,8 @@ use serde::{Deserialize, Serialize};
 /// entrypoint = "chapters/chapter-1.typ"
 /// thumbnail = "assets/thumbnail.png"
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(deny_unknown_fields)]
 pub struct Template {
     /// A path _relative to the package's root_ which points to a directory that
@@ -44,3 +47,349 @@ pub struct Template {
     /// WebP thumbnail for the template.
     pub thumbnail: PathBuf,
 }
+
+impl Template {
+    /// The TOML keys of all fields which are required in the `template` key.
+    pub const REQUIRED_FIELDS: &'static [&'static str] = &["path", "entrypoint", "thumbnail"];
+
+    /// The TOML keys of all fields which are optional in the `template` key.
+    pub const OPTIONAL_FIELDS: &'static [&'static str] = &[];
+
+    /// Creates a new [`TemplateBuilder`] with no fields set.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::template::Template;
+    ///
+    /// let template = Template::builder()
+    ///     .path("template".into())
+    ///     .entrypoint("main.typ".into())
+    ///     .thumbnail("thumbnail.png".into())
+    ///     .build()?;
+    ///
+    /// assert_eq!(template.path, std::path::Path::new("template"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn builder() -> TemplateBuilder {
+        TemplateBuilder::default()
+    }
+
+    /// The path of the template directory within the package at
+    /// `package_root`. The returned path may not exist until the template
+    /// directory is present.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::template::Template;
+    /// use std::path::Path;
+    ///
+    /// let template = Template {
+    ///     path: "template".into(),
+    ///     entrypoint: "main.typ".into(),
+    ///     thumbnail: "thumbnail.png".into(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     template.path_absolute(Path::new("/package")),
+    ///     Path::new("/package/template"),
+    /// );
+    /// ```
+    pub fn path_absolute(&self, package_root: &Path) -> PathBuf {
+        package_root.join(&self.path)
+    }
+
+    /// The path of the template entrypoint within the package at
+    /// `package_root`. The returned path may not exist until the template
+    /// directory is present.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::template::Template;
+    /// use std::path::Path;
+    ///
+    /// let template = Template {
+    ///     path: "template".into(),
+    ///     entrypoint: "main.typ".into(),
+    ///     thumbnail: "thumbnail.png".into(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     template.entrypoint_absolute(Path::new("/package")),
+    ///     Path::new("/package/template/main.typ"),
+    /// );
+    /// ```
+    pub fn entrypoint_absolute(&self, package_root: &Path) -> PathBuf {
+        self.path_absolute(package_root).join(&self.entrypoint)
+    }
+
+    /// Recursively copies the files of this template within the package at
+    /// `package_root` into `dest`, keeping their paths relative to the template
+    /// directory. Missing directories in `dest` are created. Symbolic links in
+    /// the template directory are skipped, neither they nor their targets are
+    /// copied.
+    ///
+    /// Returns an error if reading or writing fails, returns an error of kind
+    /// [`AlreadyExists`][io::ErrorKind::AlreadyExists] if a file already exists
+    /// in `dest`, files copied before the error are not removed. Returns an
+    /// error of kind [`InvalidInput`][io::ErrorKind::InvalidInput] without
+    /// copying anything if `dest` is inside the template directory.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use typst_project::manifest::Manifest;
+    ///
+    /// let manifest = Manifest::from_directory("my-template")?;
+    /// if let Some(template) = &manifest.template {
+    ///     template.copy_to("my-template", "my-project")?;
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn copy_to<P: AsRef<Path>>(&self, package_root: P, dest: P) -> io::Result<()> {
+        fn inner(src: &Path, dest: &Path) -> io::Result<()> {
+            fs::create_dir_all(dest)?;
+
+            for entry in fs::read_dir(src)? {
+                let entry = entry?;
+                let typ = entry.file_type()?;
+                let dest = dest.join(entry.file_name());
+
+                if typ.is_symlink() {
+                    continue;
+                }
+
+                if typ.is_dir() {
+                    inner(&entry.path(), &dest)?;
+                } else {
+                    let mut from = fs::File::open(entry.path())?;
+                    let mut to = fs::File::create_new(&dest)?;
+                    io::copy(&mut from, &mut to)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        let src = fs::canonicalize(self.path_absolute(package_root.as_ref()))?;
+        if canonicalize_partial(dest.as_ref())?.starts_with(&src) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "destination is inside the template directory",
+            ));
+        }
+
+        inner(&src, dest.as_ref())
+    }
+}
+
+/// Canonicalizes the longest existing ancestor of `path` and appends the
+/// remaining components, such that paths which do not exist yet can be
+/// compared with canonical paths.
+fn canonicalize_partial(path: &Path) -> io::Result<PathBuf> {
+    let path = std::path::absolute(path)?;
+    let mut existing = path.as_path();
+    let mut missing = vec![];
+
+    loop {
+        match fs::canonicalize(existing) {
+            Ok(mut canonical) => {
+                canonical.extend(missing.into_iter().rev());
+                return Ok(canonical);
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let (Some(parent), Some(name)) = (existing.parent(), existing.file_name()) else {
+                    return Err(err);
+                };
+
+                missing.push(name);
+                existing = parent;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// A builder for [`Template`], see [`Template::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct TemplateBuilder {
+    path: Option<PathBuf>,
+    entrypoint: Option<PathBuf>,
+    thumbnail: Option<PathBuf>,
+}
+
+impl TemplateBuilder {
+    /// Sets the path of the template directory.
+    pub fn path(mut self, path: PathBuf) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the path of the template entrypoint.
+    pub fn entrypoint(mut self, entrypoint: PathBuf) -> Self {
+        self.entrypoint = Some(entrypoint);
+        self
+    }
+
+    /// Sets the path of the template thumbnail.
+    pub fn thumbnail(mut self, thumbnail: PathBuf) -> Self {
+        self.thumbnail = Some(thumbnail);
+        self
+    }
+
+    /// Builds the [`Template`], returns an error listing all required fields
+    /// which were not set.
+    pub fn build(self) -> Result<Template, TemplateBuildError> {
+        match (self.path, self.entrypoint, self.thumbnail) {
+            (Some(path), Some(entrypoint), Some(thumbnail)) => Ok(Template {
+                path,
+                entrypoint,
+                thumbnail,
+            }),
+            (path, entrypoint, thumbnail) => {
+                let missing = [
+                    ("path", path.is_none()),
+                    ("entrypoint", entrypoint.is_none()),
+                    ("thumbnail", thumbnail.is_none()),
+                ]
+                .into_iter()
+                .filter_map(|(field, missing)| missing.then_some(field))
+                .collect();
+
+                Err(TemplateBuildError { missing })
+            }
+        }
+    }
+}
+
+/// An error that can occur when building a [`Template`] with missing required
+/// fields.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("template is missing required fields: {}", .missing.join(", "))]
+pub struct TemplateBuildError {
+    /// The TOML keys of the required fields which were not set, in the order of
+    /// [`Template::REQUIRED_FIELDS`].
+    pub missing: Vec<&'static str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_to() {
+        let root = tempfile::tempdir().unwrap();
+        let template_dir = root.path().join("template");
+        fs::create_dir_all(template_dir.join("chapters")).unwrap();
+        fs::write(template_dir.join("main.typ"), "main").unwrap();
+        fs::write(template_dir.join("chapters").join("a.typ"), "a").unwrap();
+
+        let template = Template {
+            path: "template".into(),
+            entrypoint: "main.typ".into(),
+            thumbnail: "thumbnail.png".into(),
+        };
+
+        let dest = tempfile::tempdir().unwrap();
+        let project = dest.path().join("project");
+        template.copy_to(root.path(), &project).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(project.join("main.typ")).unwrap(),
+            "main"
+        );
+        assert_eq!(
+            fs::read_to_string(project.join("chapters").join("a.typ")).unwrap(),
+            "a"
+        );
+
+        let err = template.copy_to(root.path(), &project).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    }
+
+    #[test]
+    fn copy_to_inside_template() {
+        let root = tempfile::tempdir().unwrap();
+        let template_dir = root.path().join("template");
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(template_dir.join("main.typ"), "main").unwrap();
+
+        let template = Template {
+            path: "template".into(),
+            entrypoint: "main.typ".into(),
+            thumbnail: "thumbnail.png".into(),
+        };
+
+        let dest = template_dir.join("nested").join("project");
+        let err = template.copy_to(root.path(), &dest).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(!template_dir.join("nested").exists());
+
+        let err = template.copy_to(root.path(), &template_dir).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_to_skips_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let root = tempfile::tempdir().unwrap();
+        let template_dir = root.path().join("template");
+        fs::create_dir_all(template_dir.join("chapters")).unwrap();
+        fs::write(template_dir.join("main.typ"), "main").unwrap();
+        symlink(
+            template_dir.join("chapters"),
+            template_dir.join("linked-dir"),
+        )
+        .unwrap();
+        symlink(
+            template_dir.join("main.typ"),
+            template_dir.join("linked.typ"),
+        )
+        .unwrap();
+
+        let template = Template {
+            path: "template".into(),
+            entrypoint: "main.typ".into(),
+            thumbnail: "thumbnail.png".into(),
+        };
+
+        let dest = tempfile::tempdir().unwrap();
+        let project = dest.path().join("project");
+        template.copy_to(root.path(), &project).unwrap();
+
+        assert!(project.join("main.typ").is_file());
+        assert!(project.join("chapters").is_dir());
+        assert!(!project.join("linked-dir").exists());
+        assert!(!project.join("linked.typ").exists());
+    }
+
+    #[test]
+    fn builder() {
+        let template = Template::builder()
+            .path("template".into())
+            .entrypoint("main.typ".into())
+            .thumbnail("thumbnail.png".into())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            template,
+            Template {
+                path: "template".into(),
+                entrypoint: "main.typ".into(),
+                thumbnail: "thumbnail.png".into(),
+            }
+        );
+
+        let err = Template::builder()
+            .entrypoint("main.typ".into())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.missing, ["path", "thumbnail"]);
+        assert_eq!(
+            err.to_string(),
+            "template is missing required fields: path, thumbnail"
+        );
+    }
+}
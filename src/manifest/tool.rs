@@ -1,31 +1,36 @@
 //! 3rd-party tooling configuration.
 
-use std::collections::BTreeMap;
-
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use toml::Table;
+use toml::{Table, Value};
 
 use super::DeserializeError;
 
 /// The `tool` key in the manifest, this key may contain any configuration
-/// given by 3rd-party tools.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// given by 3rd-party tools. The individual tool keys are commonly given in the
+/// following form:
+/// ```toml
+/// [tool.typst-test]
+/// # ...
+///
+/// [tool.packager]
+/// # ...
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
-pub struct Tool {
-    /// The individual tool keys, these are commonly given in the following
-    /// form:
-    /// ```toml
-    /// [tool.typst-test]
-    /// # ...
-    ///
-    /// [tool.packager]
-    /// # ...
-    /// ```
-    pub keys: BTreeMap<String, Table>,
-}
+pub struct Tool(Table);
 
 impl Tool {
+    /// Get the value of the given tool key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// Get the value of the given tool key if it is a table.
+    pub fn get_table(&self, key: &str) -> Option<&Table> {
+        self.0.get(key).and_then(Value::as_table)
+    }
+
     /// Get a tool section with the given key.
     ///
     /// Returns `None` if the key doesn't exist, returns an error if the key
@@ -34,6 +39,117 @@ impl Tool {
         &self,
         tool: &str,
     ) -> Result<Option<T>, DeserializeError> {
-        self.keys.get(tool).cloned().map(T::deserialize).transpose()
+        self.0.get(tool).cloned().map(T::deserialize).transpose()
+    }
+
+    /// Inserts a value for the given tool key, returning the old value if
+    /// there was one.
+    pub fn insert(&mut self, key: String, value: Value) -> Option<Value> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes the given tool key, returning its value if there was one.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.0.remove(key)
+    }
+
+    /// The names of all tools which have a key in this section.
+    pub fn tool_names(&self) -> Vec<&str> {
+        self.0.keys().map(String::as_str).collect()
+    }
+
+    /// Merges the keys of `other` into this section. Tables present in both
+    /// are merged recursively, otherwise the values of `other` replace those
+    /// in this section.
+    pub fn merge(&mut self, other: &Tool) {
+        fn inner(this: &mut Table, other: &Table) {
+            for (key, value) in other {
+                match (this.get_mut(key), value) {
+                    (Some(Value::Table(this)), Value::Table(other)) => inner(this, other),
+                    _ => {
+                        this.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+
+        inner(&mut self.0, &other.0);
+    }
+}
+
+impl From<Table> for Tool {
+    fn from(value: Table) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Tool> for Table {
+    fn from(value: Tool) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool() -> Tool {
+        Tool::from(toml::toml! {
+            version = "0.1.0"
+
+            [typst-test]
+            tests = "tests"
+        })
+    }
+
+    #[test]
+    fn get() {
+        let tool = tool();
+        assert_eq!(tool.get("version").and_then(Value::as_str), Some("0.1.0"));
+        assert!(tool.get("typst-test").is_some_and(Value::is_table));
+        assert_eq!(tool.get("foo"), None);
+    }
+
+    #[test]
+    fn get_table() {
+        let tool = tool();
+        assert_eq!(
+            tool.get_table("typst-test")
+                .and_then(|t| t.get("tests"))
+                .and_then(Value::as_str),
+            Some("tests")
+        );
+        assert_eq!(tool.get_table("version"), None);
+        assert_eq!(tool.get_table("foo"), None);
+    }
+
+    #[test]
+    fn insert() {
+        let mut tool = tool();
+        assert_eq!(tool.insert("foo".into(), Value::Integer(1)), None);
+        assert_eq!(tool.get("foo"), Some(&Value::Integer(1)));
+        assert_eq!(
+            tool.insert("foo".into(), Value::Integer(2)),
+            Some(Value::Integer(1))
+        );
+        assert_eq!(tool.get("foo"), Some(&Value::Integer(2)));
+    }
+
+    #[test]
+    fn remove() {
+        let mut tool = tool();
+        assert_eq!(tool.remove("version"), Some(Value::String("0.1.0".into())));
+        assert_eq!(tool.get("version"), None);
+        assert_eq!(tool.remove("version"), None);
+        assert_eq!(tool.remove("foo"), None);
+    }
+
+    #[test]
+    fn tool_names() {
+        let tool = tool();
+        let mut names = tool.tool_names();
+        names.sort();
+        assert_eq!(names, ["typst-test", "version"]);
+        assert!(Tool::default().tool_names().is_empty());
     }
 }
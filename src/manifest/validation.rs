@@ -0,0 +1,159 @@
+//! Semantic validation of typst manifests.
+
+use std::path::Path;
+
+use thiserror::Error;
+
+use super::Manifest;
+
+/// A semantic error in a manifest, these are errors which are not caught by
+/// parsing alone.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("package entrypoint must be a relative path")]
+    AbsoluteEntrypoint,
+
+    #[error("package entrypoint must have a `.typ` extension")]
+    BadEntrypointExtension,
+
+    #[error("package description must not be empty")]
+    EmptyDescription,
+
+    #[error("package must have at least one author")]
+    NoAuthors,
+
+    #[error("author names must not be empty")]
+    EmptyAuthorName,
+
+    #[error("template path must be a relative path")]
+    AbsoluteTemplatePath,
+
+    #[error("template entrypoint must be a relative path")]
+    AbsoluteTemplateEntrypoint,
+
+    #[error("template entrypoint must have a `.typ` extension")]
+    BadTemplateEntrypointExtension,
+
+    #[error("template thumbnail must be a relative path")]
+    AbsoluteThumbnail,
+
+    #[error("template thumbnail must have a `.png` or `.webp` extension")]
+    BadThumbnailExtension,
+}
+
+/// Returns `true` if `path` has the given extension.
+pub(crate) fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| extensions.contains(&ext))
+}
+
+/// Collects all validation errors of the given manifest.
+pub(crate) fn validate(manifest: &Manifest) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    let package = &manifest.package;
+
+    if package.entrypoint.is_absolute() {
+        errors.push(ValidationError::AbsoluteEntrypoint);
+    }
+
+    if !has_extension(&package.entrypoint, &["typ"]) {
+        errors.push(ValidationError::BadEntrypointExtension);
+    }
+
+    if package.description_is_empty() {
+        errors.push(ValidationError::EmptyDescription);
+    }
+
+    if package.authors.is_empty() {
+        errors.push(ValidationError::NoAuthors);
+    }
+
+    if package.authors.iter().any(|a| a.name.trim().is_empty()) {
+        errors.push(ValidationError::EmptyAuthorName);
+    }
+
+    if let Some(template) = &manifest.template {
+        if template.path.is_absolute() {
+            errors.push(ValidationError::AbsoluteTemplatePath);
+        }
+
+        if template.entrypoint.is_absolute() {
+            errors.push(ValidationError::AbsoluteTemplateEntrypoint);
+        }
+
+        if !has_extension(&template.entrypoint, &["typ"]) {
+            errors.push(ValidationError::BadTemplateEntrypointExtension);
+        }
+
+        if template.thumbnail.is_absolute() {
+            errors.push(ValidationError::AbsoluteThumbnail);
+        }
+
+        if !has_extension(&template.thumbnail, &["png", "webp"]) {
+            errors.push(ValidationError::BadThumbnailExtension);
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        let manifest = Manifest::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "/src/lib.rs"
+            authors = [" <@reknih>"]
+            license = "MIT"
+            description = " "
+
+            [template]
+            path = "template"
+            entrypoint = "main.typ"
+            thumbnail = "thumbnail.jpg"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.validate().unwrap_err(),
+            [
+                ValidationError::AbsoluteEntrypoint,
+                ValidationError::BadEntrypointExtension,
+                ValidationError::EmptyDescription,
+                ValidationError::EmptyAuthorName,
+                ValidationError::BadThumbnailExtension,
+            ]
+        );
+    }
+
+    #[test]
+    fn valid() {
+        let manifest = Manifest::from_str(
+            r#"
+            [package]
+            name = "foo"
+            version = "0.1.0"
+            entrypoint = "src/lib.typ"
+            authors = ["Martin <@reknih>"]
+            license = "MIT"
+            description = "Bar"
+
+            [template]
+            path = "template"
+            entrypoint = "main.typ"
+            thumbnail = "thumbnail.webp"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.validate(), Ok(()));
+    }
+}
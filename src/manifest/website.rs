@@ -1,43 +1,395 @@
+use std::str::FromStr;
+
+use serde::de::{Deserializer, Visitor};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use url::Url;
 
-// TODO: maybe simply use the url crate here
+use crate::define_str_eq;
 
-use crate::{define_conversions, define_formatting, define_serde};
+/// Checks whether `s` is a valid website without constructing a [`Website`].
+///
+/// Returns an error if `s` is not a valid website.
+pub fn validate_website(s: &str) -> Result<(), ParseWebsiteError> {
+    is_valid_website(s).map(|_| ())
+}
 
-fn is_valid_website(s: &str) -> Result<(), ParseWebsiteError> {
+fn is_valid_website(s: &str) -> Result<Url, ParseWebsiteError> {
     fn is_legal_in_website(c: u8) -> bool {
         c.is_ascii_alphanumeric() || b"-_.~:/?#[]@!$&'()*+,;=".contains(&c)
     }
 
-    if s.as_bytes().iter().copied().all(is_legal_in_website) {
-        Ok(())
-    } else {
-        Err(ParseWebsiteError::ContainsInvalidChar)
+    if !s.as_bytes().iter().copied().all(is_legal_in_website) {
+        return Err(ParseWebsiteError::ContainsInvalidChar);
     }
+
+    Ok(Url::parse(s)?)
 }
 
+/// A website url, such as the homepage or repository of a package.
+///
+/// Websites are parsed into a [`Url`] and are displayed, compared and
+/// serialized in its serialized form, which may differ from the parsed string:
+/// - the scheme and host are lowercased and default ports are removed,
+/// - an empty path becomes `/`, such that `https://mha.ug` is stored as
+///   `https://mha.ug/`,
+/// - relative urls without a scheme, such as `mha.ug`, are rejected.
+///
+/// A website may therefore not compare equal to the string it was parsed from
+/// and serializing a manifest may rewrite its websites, serializing a manifest
+/// which was parsed from such serialized output does not change it again. See
+/// [`Website::normalize`] to further canonicalize a website.
+///
+/// # Examples
+/// ```
+/// use typst_project::manifest::website::Website;
+///
+/// let website: Website = "HTTPS://mha.ug".parse()?;
+/// assert_eq!(website, "https://mha.ug/");
+/// assert!("mha.ug".parse::<Website>().is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Website(String);
+pub struct Website(Url);
+
+impl Website {
+    /// Creates a website from an already parsed [`Url`] without validating
+    /// it again.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::website::Website;
+    /// use url::Url;
+    ///
+    /// let url = Url::parse("https://typst.app")?;
+    /// let website = Website::from_url(url.clone());
+    /// assert_eq!(website.into_url(), url);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_url(url: Url) -> Self {
+        Self(url)
+    }
+
+    /// Converts this website into the [`Url`] it wraps.
+    pub fn into_url(self) -> Url {
+        self.0
+    }
+
+    /// Creates a website pointing to the GitHub repository `namespace/repo`.
+    ///
+    /// Returns an error if either part is empty or is not a single path
+    /// segment, or if the resulting url is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use typst_project::manifest::website::Website;
+    ///
+    /// let website = Website::from_github("typst", "packages")?;
+    /// assert_eq!(website, "https://github.com/typst/packages");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_github(namespace: &str, repo: &str) -> Result<Website, ParseWebsiteError> {
+        Self::from_repository("github.com", namespace, repo, false)
+    }
+
+    /// Creates a website pointing to the GitLab repository `namespace/repo`,
+    /// the namespace may contain nested groups separated by `/`.
+    ///
+    /// Returns an error if either part is empty or contains empty path
+    /// segments, if `repo` is not a single path segment, or if the resulting url
+    /// is invalid.
+    pub fn from_gitlab(namespace: &str, repo: &str) -> Result<Website, ParseWebsiteError> {
+        Self::from_repository("gitlab.com", namespace, repo, true)
+    }
+
+    fn from_repository(
+        host: &str,
+        namespace: &str,
+        repo: &str,
+        nested: bool,
+    ) -> Result<Website, ParseWebsiteError> {
+        fn is_valid_segment(s: &str) -> bool {
+            !s.is_empty() && !s.contains(['/', '?', '#'])
+        }
+
+        let namespace_valid = if nested {
+            namespace.split('/').all(is_valid_segment)
+        } else {
+            is_valid_segment(namespace)
+        };
+
+        if !namespace_valid || !is_valid_segment(repo) {
+            return Err(ParseWebsiteError::InvalidRepositoryPath);
+        }
+
+        format!("https://{host}/{namespace}/{repo}").parse()
+    }
+
+    /// The host of this website, this is either a domain or an IP address.
+    pub fn host(&self) -> Option<&str> {
+        self.0.host_str()
+    }
+
+    /// The domain of this website, this is `None` if the host is an IP
+    /// address.
+    pub fn domain(&self) -> Option<&str> {
+        self.0.domain()
+    }
+
+    /// The path of this website, this is `/` for websites without a path.
+    pub fn path(&self) -> &str {
+        self.0.path()
+    }
+
+    /// Returns `true` if this website is hosted on `github.com`.
+    pub fn is_github(&self) -> bool {
+        self.is_hosted_on("github.com")
+    }
+
+    /// Returns `true` if this website is hosted on `gitlab.com`.
+    pub fn is_gitlab(&self) -> bool {
+        self.is_hosted_on("gitlab.com")
+    }
+
+    /// Returns `true` if this website is hosted on `ctan.org`.
+    pub fn is_ctan(&self) -> bool {
+        self.is_hosted_on("ctan.org")
+    }
+
+    /// Returns the namespace and name of the repository this website points
+    /// to, for example `("typst", "packages")` for
+    /// `https://github.com/typst/packages/tree/main`. GitLab namespaces may
+    /// contain nested groups separated by `/`.
+    ///
+    /// Returns `None` if the host is not a known hosting provider (GitHub,
+    /// GitLab or Codeberg) or if the path does not point into a repository.
+    pub fn repository_namespace_and_name(&self) -> Option<(String, String)> {
+        let segments: Vec<_> = self.0.path_segments()?.filter(|s| !s.is_empty()).collect();
+
+        let (namespace, name) = if self.is_github() || self.is_hosted_on("codeberg.org") {
+            match segments.as_slice() {
+                [namespace, name, ..] => (namespace.to_string(), *name),
+                _ => return None,
+            }
+        } else if self.is_gitlab() {
+            // everything after `/-/` refers to pages within the repository
+            let end = segments
+                .iter()
+                .position(|&s| s == "-")
+                .unwrap_or(segments.len());
+            match &segments[..end] {
+                [namespace @ .., name] if !namespace.is_empty() => (namespace.join("/"), *name),
+                _ => return None,
+            }
+        } else {
+            return None;
+        };
+
+        let name = name.strip_suffix(".git").unwrap_or(name);
+        Some((namespace, name.to_owned()))
+    }
+
+    /// Removes trailing slashes from the path of this website, such that
+    /// `https://github.com/foo/` becomes `https://github.com/foo`. The path of
+    /// a website without a path is always `/` and is kept as is.
+    pub fn strip_trailing_slash(mut self) -> Self {
+        let path = self.0.path();
+        if path.len() > 1 && path.ends_with('/') {
+            let path = path.trim_end_matches('/').to_owned();
+            self.0.set_path(&path);
+        }
+
+        self
+    }
+
+    /// Normalizes this website such that websites pointing to the same
+    /// resource compare equal. The scheme and host are always lowercased and
+    /// default ports are removed on parsing, this additionally strips trailing
+    /// slashes and removes empty queries and fragments.
+    pub fn normalize(mut self) -> Self {
+        if self.0.query() == Some("") {
+            self.0.set_query(None);
+        }
+
+        if self.0.fragment() == Some("") {
+            self.0.set_fragment(None);
+        }
+
+        self.strip_trailing_slash()
+    }
+
+    /// Checks whether this website is reachable by sending a `HEAD` request
+    /// to it. Redirects are followed.
+    ///
+    /// Returns `true` for success and redirection status codes and `false` for
+    /// client and server errors, returns an error if the request could not be
+    /// sent. This depends on the network and remote servers, it should not be
+    /// used in release-critical paths.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use typst_project::manifest::website::Website;
+    ///
+    /// async fn check(website: &Website) -> Result<(), reqwest::Error> {
+    ///     if !website.is_accessible().await? {
+    ///         println!("{website} is not accessible");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "async-check")]
+    pub async fn is_accessible(&self) -> Result<bool, reqwest::Error> {
+        let response = reqwest::Client::new().head(self.0.clone()).send().await?;
+        let status = response.status();
+        Ok(status.is_success() || status.is_redirection())
+    }
+
+    fn is_hosted_on(&self, domain: &str) -> bool {
+        self.host()
+            .is_some_and(|host| host.strip_prefix("www.").unwrap_or(host) == domain)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Website {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use crate::utils::{arbitrary_str, LOWER_ALNUM};
+
+        let segment = [LOWER_ALNUM, b"-_"].concat();
+        let mut website = format!(
+            "https://{}.{}",
+            arbitrary_str(u, LOWER_ALNUM, LOWER_ALNUM, 16)?,
+            u.choose(&["app", "com", "dev", "org"])?,
+        );
+        for _ in 0..u.int_in_range(0..=3)? {
+            website.push('/');
+            website.push_str(&arbitrary_str(u, LOWER_ALNUM, &segment, 12)?);
+        }
+
+        website
+            .parse()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl std::ops::Deref for Website {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_str()
+    }
+}
+
+impl std::convert::AsRef<str> for Website {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl std::convert::AsRef<Url> for Website {
+    fn as_ref(&self) -> &Url {
+        &self.0
+    }
+}
+
+impl std::convert::From<Website> for String {
+    fn from(value: Website) -> Self {
+        value.0.into()
+    }
+}
+
+impl std::convert::TryFrom<String> for Website {
+    type Error = ParseWebsiteError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+define_str_eq!(Website);
 
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum ParseWebsiteError {
     #[error("url contained invalid byte")]
     ContainsInvalidChar,
+
+    #[error("invalid url")]
+    InvalidUrl(#[from] url::ParseError),
+
+    #[error("invalid repository path")]
+    InvalidRepositoryPath,
+}
+
+impl std::str::FromStr for Website {
+    type Err = ParseWebsiteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = is_valid_website(s)?;
+        Ok(Self(url))
+    }
+}
+
+impl std::fmt::Display for Website {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.0.as_str(), f)
+    }
 }
 
-define_formatting!(Website);
-define_conversions!(Website, ParseWebsiteError, is_valid_website);
-define_serde!(Website, ParseWebsiteError, is_valid_website, "a website");
+impl std::fmt::Debug for Website {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.0.as_str(), f)
+    }
+}
+
+impl Serialize for Website {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Website {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct WebsiteVisitor;
+
+        impl<'de> Visitor<'de> for WebsiteVisitor {
+            type Value = Website;
+
+            fn expecting(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "a website")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: ::serde::de::Error,
+            {
+                Website::from_str(v)
+                    .map_err(|_| E::invalid_value(::serde::de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(WebsiteVisitor)
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
+    use crate::manifest::{package, Manifest};
     use crate::{assert_err, assert_ok};
 
     #[test]
     fn invalid() {
         assert_err!(is_valid_website("http://mha ug"));
         assert_err!(is_valid_website("http://mhä.ug"));
+        assert_err!(is_valid_website("mha.ug"));
     }
 
     #[test]
@@ -45,4 +397,157 @@ mod tests {
         assert_ok!(is_valid_website("https://mha.ug"));
         assert_ok!(is_valid_website("https://github.com/tingerrr/hydra"));
     }
+
+    #[test]
+    fn hosts() {
+        let github = Website::from_str("https://github.com/tingerrr/hydra").unwrap();
+        assert_eq!(github.host(), Some("github.com"));
+        assert!(github.is_github());
+        assert!(!github.is_gitlab());
+
+        let ctan = Website::from_str("https://www.ctan.org/pkg/hydra").unwrap();
+        assert!(ctan.is_ctan());
+        assert!(!ctan.is_github());
+
+        let gitlab = Website::from_str("https://GitLab.com/foo/bar").unwrap();
+        assert!(gitlab.is_gitlab());
+        assert_eq!(gitlab.domain(), Some("gitlab.com"));
+        assert_eq!(gitlab.path(), "/foo/bar");
+
+        let ip = Website::from_str("http://127.0.0.1").unwrap();
+        assert_eq!(ip.host(), Some("127.0.0.1"));
+        assert_eq!(ip.domain(), None);
+        assert_eq!(ip.path(), "/");
+    }
+
+    #[test]
+    fn manifest_roundtrip() {
+        let mut manifest = Manifest::package(package::tests::package());
+        manifest.package.homepage = Some(Website::from_str("https://mha.ug").unwrap());
+        manifest.package.repository =
+            Some(Website::from_str("https://github.com/tingerrr/typst-project/").unwrap());
+
+        let string = manifest.to_toml_string().unwrap();
+        assert!(string.contains(r#"homepage = "https://mha.ug/""#));
+        assert!(string.contains(r#"repository = "https://github.com/tingerrr/typst-project/""#));
+
+        let roundtrip = Manifest::from_str(&string).unwrap();
+        assert_eq!(roundtrip, manifest);
+        assert_eq!(roundtrip.to_toml_string().unwrap(), string);
+    }
+
+    #[test]
+    fn str_eq() {
+        let website = Website::from_str("https://mha.ug/").unwrap();
+        assert_eq!(website, "https://mha.ug/");
+        assert_eq!(String::from("https://mha.ug/"), website);
+        assert_ne!(website, "https://mha.ug");
+    }
+
+    #[test]
+    fn url() {
+        let url = Url::parse("https://mha.ug/").unwrap();
+        let website = Website::from_url(url.clone());
+        assert_eq!(website, Website::from_str("https://mha.ug/").unwrap());
+        assert_eq!(website.into_url(), url);
+    }
+
+    #[test]
+    fn normalize() {
+        fn normalize(s: &str) -> String {
+            Website::from_str(s).unwrap().normalize().to_string()
+        }
+
+        assert_eq!(
+            normalize("HTTPS://GitHub.com:443/foo/"),
+            "https://github.com/foo"
+        );
+        assert_eq!(
+            normalize("https://github.com/foo//?#"),
+            "https://github.com/foo"
+        );
+        assert_eq!(
+            normalize("https://github.com/foo/?a=b#c"),
+            "https://github.com/foo?a=b#c"
+        );
+        assert_eq!(normalize("https://github.com"), "https://github.com/");
+    }
+
+    #[test]
+    fn from_repository() {
+        let github = Website::from_github("tingerrr", "hydra").unwrap();
+        assert_eq!(github, "https://github.com/tingerrr/hydra");
+        assert!(github.is_github());
+
+        let gitlab = Website::from_gitlab("typst/sub", "packages").unwrap();
+        assert_eq!(gitlab, "https://gitlab.com/typst/sub/packages");
+        assert_eq!(
+            gitlab.repository_namespace_and_name(),
+            Some(("typst/sub".into(), "packages".into()))
+        );
+
+        assert_eq!(
+            Website::from_github("typst/sub", "packages"),
+            Err(ParseWebsiteError::InvalidRepositoryPath)
+        );
+        assert_eq!(
+            Website::from_gitlab("typst//sub", "packages"),
+            Err(ParseWebsiteError::InvalidRepositoryPath)
+        );
+        assert_eq!(
+            Website::from_github("", "packages"),
+            Err(ParseWebsiteError::InvalidRepositoryPath)
+        );
+        assert_eq!(
+            Website::from_github("ty pst", "packages"),
+            Err(ParseWebsiteError::ContainsInvalidChar)
+        );
+    }
+
+    #[test]
+    fn repository_namespace_and_name() {
+        fn parts(s: &str) -> Option<(String, String)> {
+            Website::from_str(s)
+                .unwrap()
+                .repository_namespace_and_name()
+        }
+
+        let expected = Some(("typst".into(), "packages".into()));
+        assert_eq!(parts("https://github.com/typst/packages"), expected);
+        assert_eq!(parts("https://github.com/typst/packages.git"), expected);
+        assert_eq!(
+            parts("https://github.com/typst/packages/tree/main"),
+            expected
+        );
+        assert_eq!(parts("https://codeberg.org/typst/packages/"), expected);
+        assert_eq!(
+            parts("https://gitlab.com/typst/packages/-/tree/main"),
+            expected
+        );
+        assert_eq!(
+            parts("https://gitlab.com/typst/sub/packages"),
+            Some(("typst/sub".into(), "packages".into()))
+        );
+
+        assert_eq!(parts("https://github.com/typst"), None);
+        assert_eq!(parts("https://gitlab.com/typst/-/packages"), None);
+        assert_eq!(parts("https://typst.app/typst/packages"), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_roundtrip(s in "https?://[a-zA-Z0-9.-]{1,16}(/[a-zA-Z0-9._~-]{0,8}){0,3}|\\PC{0,24}") {
+            if let Ok(website) = Website::from_str(&s) {
+                prop_assert_eq!(Website::from_str(&website), Ok(website));
+            }
+        }
+
+        #[test]
+        fn prop_invalid_char(s in "https://[a-z]{1,8}[ <>\"{}|^`][a-z]{0,8}") {
+            prop_assert_eq!(
+                Website::from_str(&s),
+                Err(ParseWebsiteError::ContainsInvalidChar)
+            );
+        }
+    }
 }
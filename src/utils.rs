@@ -1,3 +1,43 @@
+use std::collections::HashSet;
+
+use serde::{Serialize, Serializer};
+
+/// Serializes a set as a sorted sequence, such that the output does not
+/// depend on the iteration order of the set.
+pub(crate) fn serialize_sorted<S, T>(set: &HashSet<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + Ord,
+{
+    let mut items: Vec<_> = set.iter().collect();
+    items.sort();
+    serializer.collect_seq(items)
+}
+
+/// Lowercase ASCII letters and digits.
+#[cfg(feature = "arbitrary")]
+pub(crate) const LOWER_ALNUM: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Generates an arbitrary string with a length between 1 and `max` bytes,
+/// whose first byte is taken from `first` and all other bytes from `rest`.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_str(
+    u: &mut arbitrary::Unstructured<'_>,
+    first: &[u8],
+    rest: &[u8],
+    max: usize,
+) -> arbitrary::Result<String> {
+    let len = u.int_in_range(1..=max)?;
+    let mut s = String::with_capacity(len);
+
+    s.push(char::from(*u.choose(first)?));
+    for _ in 1..len {
+        s.push(char::from(*u.choose(rest)?));
+    }
+
+    Ok(s)
+}
+
 #[macro_export]
 macro_rules! define_conversions {
     ($type:ident, $err_type:ident, $validator:ident) => {
@@ -67,6 +107,13 @@ macro_rules! define_serde {
                         write!(f, $expecting)
                     }
 
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: ::serde::de::Error,
+                    {
+                        self.visit_string(v.to_owned())
+                    }
+
                     fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
                     where
                         E: ::serde::de::Error,
@@ -101,6 +148,62 @@ macro_rules! define_formatting {
     };
 }
 
+#[macro_export]
+macro_rules! define_formatting_prefixed {
+    ($type:ident) => {
+        impl ::std::fmt::Display for $type {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::fmt::Debug for $type {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}({:?})", stringify!($type), &self.0)
+            }
+        }
+    };
+}
+
+/// Defines the conversions, serde implementations and formatting of a string
+/// newtype, prefix the type with `prefixed` to use
+/// [`define_formatting_prefixed`] instead of [`define_formatting`].
+#[macro_export]
+macro_rules! define_newtype {
+    (prefixed $type:ident, $err_type:ident, $validator:ident, $expecting:literal) => {
+        $crate::define_formatting_prefixed!($type);
+        $crate::define_conversions!($type, $err_type, $validator);
+        $crate::define_serde!($type, $err_type, $validator, $expecting);
+    };
+    ($type:ident, $err_type:ident, $validator:ident, $expecting:literal) => {
+        $crate::define_formatting!($type);
+        $crate::define_conversions!($type, $err_type, $validator);
+        $crate::define_serde!($type, $err_type, $validator, $expecting);
+    };
+}
+
+#[macro_export]
+macro_rules! define_str_eq {
+    ($type:ident) => {
+        $crate::define_str_eq!(@impl $type, str);
+        $crate::define_str_eq!(@impl $type, &str);
+        $crate::define_str_eq!(@impl $type, String);
+    };
+    (@impl $type:ident, $other:ty) => {
+        impl ::std::cmp::PartialEq<$other> for $type {
+            fn eq(&self, other: &$other) -> bool {
+                <$type as ::std::convert::AsRef<str>>::as_ref(self) == &other[..]
+            }
+        }
+
+        impl ::std::cmp::PartialEq<$type> for $other {
+            fn eq(&self, other: &$type) -> bool {
+                &self[..] == <$type as ::std::convert::AsRef<str>>::as_ref(other)
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! assert_ok {
     ($res:expr $(,)?) => {